@@ -7,10 +7,21 @@ struct RenderData {
     vertex_buffer: <Context as HasContext>::Buffer,
     element_buffer: <Context as HasContext>::Buffer,
     texture_atlas_uniform: Option<<Context as HasContext>::UniformLocation>,
+    drawing_image_uniform: Option<<Context as HasContext>::UniformLocation>,
     texture: <Context as HasContext>::Texture,
 }
 pub struct GLDrawer {
     render_data: RenderData,
+    /// A texture for each image in the order they were added.
+    image_textures: Vec<<Context as HasContext>::Texture>,
+}
+
+/// A range of indices drawn with the same clip rectangle and image.
+struct Batch {
+    start: usize,
+    count: usize,
+    clip: Option<(f32, f32, f32, f32)>,
+    image: Option<usize>,
 }
 #[derive(Debug)]
 struct Vec4 {
@@ -69,6 +80,7 @@ impl GLDrawer {
 
         unsafe {
             let texture_atlas_uniform = gl.get_uniform_location(program, "textureAtlas");
+            let drawing_image_uniform = gl.get_uniform_location(program, "drawingImage");
 
             // Setup and bind the vao
             let vertex_array_object = gl.create_vertex_array().unwrap();
@@ -121,11 +133,15 @@ impl GLDrawer {
                 vertex_buffer,
                 element_buffer,
                 texture_atlas_uniform,
+                drawing_image_uniform,
                 texture,
             };
             panic_if_error(gl);
 
-            GLDrawer { render_data }
+            GLDrawer {
+                render_data,
+                image_textures: Vec::new(),
+            }
         }
     }
 
@@ -253,13 +269,27 @@ impl GLDrawer {
     }
 
     // Does not update the texture yet.
-    fn update_data(&mut self, gl: &Context, drawing_info: &DrawingInfo) -> usize {
+    fn update_data(&mut self, gl: &Context, drawing_info: &DrawingInfo) -> Vec<Batch> {
         panic_if_error(gl);
 
         let mut vertices = Vec::new();
         let mut indices = Vec::new();
+        let mut batches: Vec<Batch> = Vec::new();
 
         for drawable in &drawing_info.drawables {
+            // Start a new batch whenever the clip rectangle or image changes.
+            if batches
+                .last()
+                .is_none_or(|b| b.clip != drawable.clip || b.image != drawable.image)
+            {
+                batches.push(Batch {
+                    start: indices.len(),
+                    count: 0,
+                    clip: drawable.clip,
+                    image: drawable.image,
+                });
+            }
+
             let vertices_len = vertices.len() as u32;
             let r0 = drawable.rectangle;
 
@@ -295,11 +325,12 @@ impl GLDrawer {
                 //  println!("t2: {:?}", t2);
                 // println!("t3: {:?}", t3);
 
-                let c = drawable.color;
-                vertices.push(Vertex::new((r.0, r.1, 0., 0.), t0, c));
-                vertices.push(Vertex::new((r.0, r.1 + r.3, 0., 0.), t1, c));
-                vertices.push(Vertex::new((r.0 + r.2, r.1 + r.3, 0., 0.), t2, c));
-                vertices.push(Vertex::new((r.0 + r.2, r.1, 0., 0.), t3, c));
+                // Colors are interpolated between vertices, which draws gradients.
+                let c = drawable.gradient.unwrap_or([drawable.color; 4]);
+                vertices.push(Vertex::new((r.0, r.1, 0., 0.), t0, c[0]));
+                vertices.push(Vertex::new((r.0, r.1 + r.3, 0., 0.), t1, c[3]));
+                vertices.push(Vertex::new((r.0 + r.2, r.1 + r.3, 0., 0.), t2, c[2]));
+                vertices.push(Vertex::new((r.0 + r.2, r.1, 0., 0.), t3, c[1]));
 
                 indices.push(vertices_len + 0);
                 indices.push(vertices_len + 1);
@@ -308,6 +339,9 @@ impl GLDrawer {
                 indices.push(vertices_len + 2);
                 indices.push(vertices_len + 3);
             }
+
+            let batch = batches.last_mut().unwrap();
+            batch.count = indices.len() - batch.start;
         }
 
         unsafe {
@@ -319,11 +353,39 @@ impl GLDrawer {
             gl.bind_buffer(ELEMENT_ARRAY_BUFFER, Some(self.render_data.element_buffer));
             gl.buffer_data_u8_slice(ELEMENT_ARRAY_BUFFER, slice_to_bytes(&indices), STATIC_DRAW);
         }
-        indices.len()
+        batches
+    }
+
+    /// Creates textures for images added since the last draw.
+    fn update_images(&mut self, gl: &Context, drawing_info: &DrawingInfo) {
+        for image in &drawing_info.images[self.image_textures.len()..] {
+            unsafe {
+                let texture = gl.create_texture().unwrap();
+                gl.bind_texture(TEXTURE_2D, Some(texture));
+                gl.tex_parameter_i32(TEXTURE_2D, TEXTURE_WRAP_S, CLAMP_TO_EDGE as i32);
+                gl.tex_parameter_i32(TEXTURE_2D, TEXTURE_WRAP_T, CLAMP_TO_EDGE as i32);
+                gl.tex_parameter_i32(TEXTURE_2D, TEXTURE_MIN_FILTER, LINEAR as i32);
+                gl.tex_parameter_i32(TEXTURE_2D, TEXTURE_MAG_FILTER, LINEAR as i32);
+                gl.tex_image_2d(
+                    TEXTURE_2D,
+                    0,
+                    RGBA as i32,
+                    image.width as i32,
+                    image.height as i32,
+                    0,
+                    RGBA,
+                    UNSIGNED_BYTE,
+                    Some(&image.data),
+                );
+                panic_if_error(gl);
+                self.image_textures.push(texture);
+            }
+        }
     }
 
     pub fn draw(&mut self, gl: &Context, drawing_info: &DrawingInfo) {
-        let index_count = self.update_data(gl, drawing_info);
+        let batches = self.update_data(gl, drawing_info);
+        self.update_images(gl, drawing_info);
 
         //  println!("DRAWABLE VERTICES: {:?}", drawable.vertices);
         unsafe {
@@ -372,9 +434,39 @@ impl GLDrawer {
             gl.uniform_1_i32(self.render_data.texture_atlas_uniform.as_ref(), 0);
             panic_if_error(gl);
 
-            //s  println!("Drawing: {:?}", index_count);
-            gl.draw_elements(TRIANGLES, index_count as i32, UNSIGNED_INT, 0);
-            panic_if_error(gl);
+            for batch in batches {
+                // Images are drawn with their own texture in place of the glyph atlas.
+                let texture = match batch.image {
+                    Some(image) => self.image_textures[image],
+                    None => self.render_data.texture,
+                };
+                gl.bind_texture(TEXTURE_2D, Some(texture));
+                gl.uniform_1_i32(
+                    self.render_data.drawing_image_uniform.as_ref(),
+                    batch.image.is_some() as i32,
+                );
+
+                // Clip rectangles are converted to the scissor's coordinates with 0, 0 in the lower left.
+                if let Some((x, y, width, height)) = batch.clip {
+                    gl.enable(SCISSOR_TEST);
+                    gl.scissor(
+                        x as i32,
+                        (drawing_info.canvas_height - y - height) as i32,
+                        width.max(0.) as i32,
+                        height.max(0.) as i32,
+                    );
+                } else {
+                    gl.disable(SCISSOR_TEST);
+                }
+                gl.draw_elements(
+                    TRIANGLES,
+                    batch.count as i32,
+                    UNSIGNED_INT,
+                    (batch.start * std::mem::size_of::<u32>()) as i32,
+                );
+                panic_if_error(gl);
+            }
+            gl.disable(SCISSOR_TEST);
         }
     }
 }
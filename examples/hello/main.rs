@@ -29,15 +29,12 @@ fn main() {
     let gl = unsafe { glow::Context::from_loader_function(|s| gl_context.get_proc_address(s)) };
 
     unsafe {
-        // gl.enable(SCISSOR_TEST);
         gl.viewport(0, 0, window_width as i32, window_height as i32);
     }
 
     let mut ui = UI::new();
     let inter_medium = ui.font_from_bytes(include_bytes!("../../resources/Inter-Medium.ttf")); //&std::fs::read("resources/Inter-Medium.ttf").unwrap());
-                                                                                               /*let material_icons =
-                                                                                               ui.font_from_bytes(include_bytes!("../../resources/MaterialIcons-Regular.ttf")); //&std::fs::read("resources/MaterialIcons-Regular.ttf").unwrap());
-                                                                                               */
+    ui.icon_font_from_bytes(include_bytes!("../../resources/MaterialIcons-Regular.ttf"));
     ui.resize(window_width as f32, window_height as f32);
 
     const GRAY: (f32, f32, f32, f32) = (0.6, 0.6, 0.6, 1.0);
@@ -62,16 +59,28 @@ fn main() {
             ui.pointer_move(x as f32, y as f32);
             window.request_redraw();
         }
-        Event::PointerDown { x, y, .. } => {
-            ui.pointer_down(x as f32, y as f32);
+        Event::PointerDown {
+            x,
+            y,
+            button: PointerButton::Primary,
+            ..
+        } => {
+            ui.pointer_down(x as f32, y as f32, kui::input::PointerButton::Primary);
             window.request_redraw();
         }
-        Event::PointerUp { x, y, .. } => {
-            ui.pointer_up(x as f32, y as f32);
+        Event::PointerUp {
+            x,
+            y,
+            button: PointerButton::Primary,
+            ..
+        } => {
+            ui.pointer_up(x as f32, y as f32, kui::input::PointerButton::Primary);
             window.request_redraw();
         }
-        Event::Scroll { delta_y, .. } => {
-            ui.scroll(delta_y as f32);
+        Event::Scroll {
+            delta_x, delta_y, ..
+        } => {
+            ui.scroll(delta_x as f32, delta_y as f32);
             window.request_redraw();
         }
         Event::Draw { .. } => unsafe {
@@ -6,10 +6,14 @@ out vec4 color_out;
 
 // Includes all characters and icons to be used for rendering.
 uniform sampler2D textureAtlas;
+// Set when an image is bound in place of the atlas.
+uniform bool drawingImage;
 
 void main()
 {   
-    if (texturePos.x != 0 || texturePos.y != 0) {
+    if (drawingImage) {
+        color_out = texture(textureAtlas, texturePos) * color;
+    } else if (texturePos.x != 0 || texturePos.y != 0) {
         color_out = vec4(color.rgb, texture(textureAtlas, texturePos).r);
     } else {
         color_out = color;
@@ -8,14 +8,20 @@ async fn ui(app: Application, events: Events) {
     let mut ui = SimpleUI::new(app, events);
     let inter_medium = ui.new_font(include_bytes!("../resources/Inter-Medium.ttf"));
 
+    let mut text = String::new();
+    let mut submitted = String::new();
+
     loop {
         // Wait until the next time there is a user event or need to redraw.
         ui.update().await;
 
         // Construct the UI
         let body = ui.edit().font(inter_medium);
-        let row = body.spaced_row(10.);
+        let column = body.spaced_column(10.);
 
-        text_field(&row, "Hello");
+        if text_field(&column.width(400.), &mut text, "Hello").submitted {
+            submitted = text.clone();
+        }
+        column.text(&submitted);
     }
 }
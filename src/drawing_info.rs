@@ -4,6 +4,21 @@ pub struct Drawable {
     pub texture_rectangle: (f32, f32, f32, f32),
     pub color: (f32, f32, f32, f32),
     pub radiuses: Option<(f32, f32, f32, f32)>,
+    /// Colors for the top left, top right, bottom right and bottom left corners that are
+    /// blended across the rectangle instead of using `color`.
+    pub gradient: Option<[(f32, f32, f32, f32); 4]>,
+    /// The index of an image in `DrawingInfo::images` to draw instead of the glyph atlas.
+    pub image: Option<usize>,
+    /// Only the part of the drawable within this rectangle should be drawn.
+    pub clip: Option<(f32, f32, f32, f32)>,
+}
+
+/// An image that can be drawn with `ElementType::Image`.
+pub struct Image {
+    pub width: u32,
+    pub height: u32,
+    /// Four bytes (red, green, blue and alpha) for each pixel, row by row from the top left.
+    pub data: Vec<u8>,
 }
 
 pub struct DrawingInfo {
@@ -11,6 +26,8 @@ pub struct DrawingInfo {
     pub canvas_height: f32,
     pub texture: crate::texture::Texture,
     pub drawables: Vec<Drawable>,
+    /// Images are never removed, so an image's index stays the same.
+    pub images: Vec<Image>,
     pub(crate) characters: Vec<(usize, fontdue::layout::GlyphRasterConfig)>,
 }
 
@@ -0,0 +1,81 @@
+//! Input types shared by the UI and the windowing backends.
+
+/// Keys the UI understands.
+/// Backends translate their own key codes to these before passing them to the UI.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Key {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+    I,
+    J,
+    K,
+    L,
+    M,
+    N,
+    O,
+    P,
+    Q,
+    R,
+    S,
+    T,
+    U,
+    V,
+    W,
+    X,
+    Y,
+    Z,
+    Digit0,
+    Digit1,
+    Digit2,
+    Digit3,
+    Digit4,
+    Digit5,
+    Digit6,
+    Digit7,
+    Digit8,
+    Digit9,
+    Left,
+    Right,
+    Up,
+    Down,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    Tab,
+    Escape,
+    Enter,
+    Backspace,
+    Delete,
+    Space,
+    Shift,
+    Control,
+    Alt,
+    Meta,
+}
+
+/// Which modifier keys are currently held down.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Modifiers {
+    pub shift: bool,
+    pub control: bool,
+    pub alt: bool,
+    pub meta: bool,
+}
+
+/// Buttons on a mouse or other pointing device.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PointerButton {
+    /// Usually the left mouse button or a touch.
+    Primary,
+    /// Usually the right mouse button.
+    Secondary,
+    /// Usually the middle mouse button or scroll wheel click.
+    Middle,
+}
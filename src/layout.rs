@@ -1,11 +1,14 @@
 //! Layout is responsible for determining the sizing of each element.
+use crate::drawing_info::Image;
 use crate::rectangle::Rectangle;
+use crate::text::layout_text;
 use crate::tree::{NodeHandle, Tree};
 use crate::ui::{Element, ElementType, TextProperties};
 
 /// Layout borrows things from the UI
 pub(crate) struct Layout<'a> {
     pub(crate) fonts: &'a Vec<fontdue::Font>,
+    pub(crate) images: &'a Vec<Image>,
     pub(crate) tree: &'a Tree,
     pub(crate) elements: &'a mut Vec<Element>,
 }
@@ -38,9 +41,12 @@ impl<'a> Layout<'a> {
             | ElementType::Flexible
             | ElementType::Fill(..)
             | ElementType::RoundedFill(..)
+            | ElementType::Gradient(..)
             | ElementType::Center(..)
+            | ElementType::Clip
             | ElementType::PositionHorizontalPercentage(_)
             | ElementType::PositionHorizontalPixels(_)
+            | ElementType::PositionVerticalPercentage(_)
             | ElementType::PositionVerticalPixels(_) => {
                 self.layout_children(parent_size, text_properties, node)
             }
@@ -48,6 +54,10 @@ impl<'a> Layout<'a> {
                 let parent_size = (parent_size.0 * percentage, parent_size.1);
                 self.layout_children(parent_size, text_properties, node)
             }
+            ElementType::HeightPercentage(percentage) => {
+                let parent_size = (parent_size.0, parent_size.1 * percentage);
+                self.layout_children(parent_size, text_properties, node)
+            }
             ElementType::ScaleToFit => {
                 let (children_width, children_height): (f32, f32) =
                     self.layout_children(parent_size, &text_properties, node);
@@ -70,8 +80,13 @@ impl<'a> Layout<'a> {
             ElementType::Padding(padding_width, padding_height) => {
                 // Padding ensures that the space is requested is at least padding.
                 // Probably padding shouldn't have to walk the tree and should just assume one child.
+                // Children have the space within the padding, so paragraphs wrap inside it.
+                let inner_size = (
+                    (parent_size.0 - padding_width * 2.).max(0.),
+                    (parent_size.1 - padding_height * 2.).max(0.),
+                );
                 let (children_width, children_height): (f32, f32) =
-                    self.layout_children(parent_size, &text_properties, node);
+                    self.layout_children(inner_size, text_properties, node);
 
                 (
                     children_width + padding_width * 2.,
@@ -94,14 +109,42 @@ impl<'a> Layout<'a> {
             ElementType::TextSize(size) => {
                 let text_properties = TextProperties {
                     size,
-                    font: text_properties.font,
+                    ..*text_properties
                 };
                 self.layout_children(parent_size, &text_properties, node)
             }
             ElementType::Font(font) => {
                 let text_properties = TextProperties {
-                    size: text_properties.size,
                     font: Some(font),
+                    ..*text_properties
+                };
+                self.layout_children(parent_size, &text_properties, node)
+            }
+            ElementType::TextAlign(align) => {
+                let text_properties = TextProperties {
+                    align,
+                    ..*text_properties
+                };
+                self.layout_children(parent_size, &text_properties, node)
+            }
+            ElementType::LineHeight(line_height) => {
+                let text_properties = TextProperties {
+                    line_height,
+                    ..*text_properties
+                };
+                self.layout_children(parent_size, &text_properties, node)
+            }
+            ElementType::TextOverflow(overflow) => {
+                let text_properties = TextProperties {
+                    overflow,
+                    ..*text_properties
+                };
+                self.layout_children(parent_size, &text_properties, node)
+            }
+            ElementType::TextColor(color) => {
+                let text_properties = TextProperties {
+                    color,
+                    ..*text_properties
                 };
                 self.layout_children(parent_size, &text_properties, node)
             }
@@ -118,39 +161,26 @@ impl<'a> Layout<'a> {
                 (width, parent_size.1)
             }
 
+            // Images request their size in pixels.
+            ElementType::Image(image) => {
+                let image = &self.images[image.0];
+                (image.width as f32, image.height as f32)
+            }
+            // Icons are square so that they line up regardless of the glyph's shape.
+            ElementType::Icon(_) => (text_properties.size, text_properties.size),
             ElementType::Text(ref text) => {
-                // Choose an arbitrary size if known is specified.
-                let text_size = text_properties.size;
                 if let Some(font) = text_properties.font {
-                    let fonts = [&self.fonts[font.0]];
-                    let text_style = fontdue::layout::TextStyle {
-                        text: &text,
-                        px: text_size,
-                        font_index: 0,
-                    };
-
-                    // Should this be used if a text size is specified?
-                    let text_height = self.fonts[font.0]
-                        .horizontal_line_metrics(text_size)
-                        .unwrap()
-                        .new_line_size;
-                    let layout_settings = fontdue::layout::LayoutSettings {
-                        ..fontdue::layout::LayoutSettings::default()
-                    };
-
-                    let mut layout_output = Vec::new();
-                    let mut layout = fontdue::layout::Layout::new();
-                    layout.layout_horizontal(
-                        &fonts,
-                        &[&text_style],
-                        &layout_settings,
-                        &mut layout_output,
+                    let text_layout = layout_text(
+                        &self.fonts[font.0],
+                        text,
+                        text_properties,
+                        None,
+                        Some(parent_size.0),
                     );
 
-                    if let Some(c) = layout_output.get(0) {
+                    if let Some(c) = text_layout.glyphs.first() {
                         let rectangle = Rectangle::new(c.x, c.y, c.width as f32, c.height as f32);
-                        let total_rectangle = layout_output.iter().fold(rectangle, |r, c| {
-                            //   println!("c.y: {:?} c.height: {:?}", c.y, c.height);
+                        let total_rectangle = text_layout.glyphs.iter().fold(rectangle, |r, c| {
                             let c_rectangle =
                                 Rectangle::new(c.x, c.y, c.width as f32, c.height as f32);
                             r.join(c_rectangle)
@@ -164,9 +194,76 @@ impl<'a> Layout<'a> {
                     (0., 0.)
                 }
             }
+            // Paragraphs wrap to the parent width and are as tall as their lines.
+            ElementType::Paragraph(ref text) => {
+                if let Some(font) = text_properties.font {
+                    let text_layout = layout_text(
+                        &self.fonts[font.0],
+                        text,
+                        text_properties,
+                        Some(parent_size.0),
+                        Some(parent_size.0),
+                    );
+                    (text_layout.width, text_layout.height)
+                } else {
+                    (0., 0.)
+                }
+            }
         };
         // x and y are unassigned until render pass
         self.elements[node.0].rectangle = Rectangle::new(0., 0., size.0, size.1);
         size
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::FontHandle;
+
+    fn element(element_type: ElementType) -> Element {
+        Element {
+            element_type,
+            rectangle: Rectangle::zero(),
+            clip: None,
+            widget: None,
+            focus_id: None,
+        }
+    }
+
+    #[test]
+    fn padded_paragraphs_wrap_within_the_padding() {
+        let font = fontdue::Font::from_bytes(
+            &include_bytes!("../resources/Inter-Medium.ttf")[..],
+            fontdue::FontSettings::default(),
+        )
+        .unwrap();
+        let fonts = vec![font];
+        let mut tree = Tree::new();
+        let padding = tree.add(None);
+        let paragraph = tree.add(Some(padding));
+        let mut elements = vec![
+            element(ElementType::Padding(20., 20.)),
+            element(ElementType::Paragraph(
+                "A paragraph that is long enough to wrap onto several lines within its padding."
+                    .to_owned(),
+            )),
+        ];
+        let text_properties = TextProperties {
+            font: Some(FontHandle(0)),
+            ..TextProperties::new()
+        };
+
+        let mut layout = Layout {
+            fonts: &fonts,
+            images: &Vec::new(),
+            tree: &tree,
+            elements: &mut elements,
+        };
+        let (width, _) = layout.layout((300., 300.), &text_properties, padding);
+        assert!(width <= 300., "{}", width);
+        assert!(elements[paragraph.0].rectangle.width <= 260.);
+        // The paragraph wrapped rather than overflowing.
+        assert!(elements[paragraph.0].rectangle.height > text_properties.size * 2.);
+    }
+}
@@ -1,12 +1,14 @@
 mod drawing_info;
+pub mod input;
 mod layout;
 mod rectangle;
 mod render;
+mod text;
 mod texture;
 mod tree;
 mod ui;
 pub mod widgets;
-pub use drawing_info::{Drawable, DrawingInfo};
+pub use drawing_info::{Drawable, DrawingInfo, Image};
 pub use render::Render;
 pub use ui::*;
 
@@ -51,6 +51,22 @@ impl Rectangle {
             height,
         }
     }
+
+    /// Calculates the area covered by both rectangles.
+    /// If they do not overlap the rectangle will have zero width or height.
+    pub fn intersection(&self, other: Rectangle) -> Self {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let width = ((other.x + other.width).min(self.x + self.width) - x).max(0.);
+        let height = ((other.y + other.height).min(self.y + self.height) - y).max(0.);
+
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -2,19 +2,47 @@
 //! A parent element makes available to the child a rectangle of space.
 use crate::drawing_info::*;
 use crate::rectangle::Rectangle;
+use crate::text::layout_text;
 use crate::tree::{NodeHandle, Tree};
-use crate::ui::{Element, ElementType, TextProperties, Widget};
+use crate::ui::{Element, ElementType, FontHandle, TextOverflow, TextProperties, Widget};
 
 /// Render borrows things from the UI
 pub struct Render<'a> {
     pub(crate) fonts: &'a Vec<fontdue::Font>,
+    pub(crate) icon_font: Option<FontHandle>,
     pub tree: &'a Tree,
     pub elements: &'a mut Vec<Element>,
     pub drawing_info: &'a mut DrawingInfo,
     pub(crate) widgets: &'a mut Vec<Option<Box<dyn Widget>>>,
+    /// Each clip rectangle is already intersected with the one before it.
+    pub(crate) clip_stack: Vec<Rectangle>,
 }
 
 impl<'a> Render<'a> {
+    /// Adds a drawable clipped to the current clip rectangle.
+    pub fn push_drawable(&mut self, drawable: Drawable) {
+        let clip = self
+            .clip_stack
+            .last()
+            .map(|r| (r.x, r.y, r.width, r.height));
+        self.drawing_info
+            .drawables
+            .push(Drawable { clip, ..drawable });
+    }
+
+    /// Clip everything drawn until the matching `pop_clip` to the rectangle.
+    pub fn push_clip(&mut self, rectangle: Rectangle) {
+        let rectangle = match self.clip_stack.last() {
+            Some(clip) => clip.intersection(rectangle),
+            None => rectangle,
+        };
+        self.clip_stack.push(rectangle);
+    }
+
+    pub fn pop_clip(&mut self) {
+        self.clip_stack.pop();
+    }
+
     pub(crate) fn render_element(
         &mut self,
         text_properties: &TextProperties,
@@ -24,6 +52,8 @@ impl<'a> Render<'a> {
         let element = &self.elements[node.0];
         let element_rectangle = element.rectangle;
         self.elements[node.0].rectangle = rectangle;
+        // Hit testing ignores the parts of elements that are clipped away.
+        self.elements[node.0].clip = self.clip_stack.last().copied();
         let element = &self.elements[node.0];
 
         match element.element_type {
@@ -155,13 +185,27 @@ impl<'a> Render<'a> {
                     self.render_element(text_properties, rectangle, child);
                 }
             }
+            ElementType::HeightPercentage(height) => {
+                let rectangle = Rectangle::new(
+                    rectangle.x,
+                    rectangle.y,
+                    rectangle.width,
+                    rectangle.height * height,
+                );
+                for child in self.tree.child_iter(node) {
+                    self.render_element(text_properties, rectangle, child);
+                }
+            }
             ElementType::Fill(color) => {
                 let fill_rectangle = (rectangle.x, rectangle.y, rectangle.width, rectangle.height);
-                self.drawing_info.drawables.push(Drawable {
+                self.push_drawable(Drawable {
                     rectangle: fill_rectangle,
                     texture_rectangle: (0., 0., 0., 0.),
                     color,
                     radiuses: None,
+                    gradient: None,
+                    image: None,
+                    clip: None,
                 });
                 // Render all children with the full size of the space.
                 for child in self.tree.child_iter(node) {
@@ -170,17 +214,65 @@ impl<'a> Render<'a> {
             }
             ElementType::RoundedFill(r, color) => {
                 let fill_rectangle = (rectangle.x, rectangle.y, rectangle.width, rectangle.height);
-                self.drawing_info.drawables.push(Drawable {
+                self.push_drawable(Drawable {
                     rectangle: fill_rectangle,
                     texture_rectangle: (0., 0., 0., 0.),
                     color,
                     radiuses: Some(r),
+                    gradient: None,
+                    image: None,
+                    clip: None,
+                });
+                // Render all children with the full size of the space.
+                for child in self.tree.child_iter(node) {
+                    self.render_element(text_properties, rectangle, child);
+                }
+            }
+            ElementType::Gradient(corner_colors) => {
+                let fill_rectangle = (rectangle.x, rectangle.y, rectangle.width, rectangle.height);
+                self.push_drawable(Drawable {
+                    rectangle: fill_rectangle,
+                    texture_rectangle: (0., 0., 0., 0.),
+                    color: corner_colors[0],
+                    radiuses: None,
+                    gradient: Some(corner_colors),
+                    image: None,
+                    clip: None,
                 });
                 // Render all children with the full size of the space.
                 for child in self.tree.child_iter(node) {
                     self.render_element(text_properties, rectangle, child);
                 }
             }
+            ElementType::Image(image) => {
+                let (width, height) = {
+                    let image = &self.drawing_info.images[image.0];
+                    (image.width as f32, image.height as f32)
+                };
+                // Images keep their aspect ratio, so only the scaled image can be hit tested.
+                let scale = (rectangle.width / width)
+                    .min(rectangle.height / height)
+                    .max(0.);
+                let (drawn_width, drawn_height) = (width * scale, height * scale);
+                self.elements[node.0].rectangle =
+                    Rectangle::new(rectangle.x, rectangle.y, drawn_width, drawn_height);
+                self.push_drawable(Drawable {
+                    rectangle: (rectangle.x, rectangle.y, drawn_width, drawn_height),
+                    texture_rectangle: (0., 0., 1., 1.),
+                    color: (1., 1., 1., 1.),
+                    radiuses: None,
+                    gradient: None,
+                    image: Some(image.0),
+                    clip: None,
+                });
+            }
+            ElementType::Clip => {
+                self.push_clip(rectangle);
+                for child in self.tree.child_iter(node) {
+                    self.render_element(text_properties, rectangle, child);
+                }
+                self.pop_clip();
+            }
             ElementType::Padding(padding_width, padding_height) => {
                 let padded_rectangle = Rectangle::new(
                     rectangle.x + padding_width,
@@ -236,7 +328,7 @@ impl<'a> Render<'a> {
             ElementType::TextSize(size) => {
                 let text_properties = TextProperties {
                     size,
-                    font: text_properties.font,
+                    ..*text_properties
                 };
                 for child in self.tree.child_iter(node) {
                     self.render_element(&text_properties, rectangle, child);
@@ -244,8 +336,44 @@ impl<'a> Render<'a> {
             }
             ElementType::Font(font) => {
                 let text_properties = TextProperties {
-                    size: text_properties.size,
                     font: Some(font),
+                    ..*text_properties
+                };
+                for child in self.tree.child_iter(node) {
+                    self.render_element(&text_properties, rectangle, child);
+                }
+            }
+            ElementType::TextAlign(align) => {
+                let text_properties = TextProperties {
+                    align,
+                    ..*text_properties
+                };
+                for child in self.tree.child_iter(node) {
+                    self.render_element(&text_properties, rectangle, child);
+                }
+            }
+            ElementType::LineHeight(line_height) => {
+                let text_properties = TextProperties {
+                    line_height,
+                    ..*text_properties
+                };
+                for child in self.tree.child_iter(node) {
+                    self.render_element(&text_properties, rectangle, child);
+                }
+            }
+            ElementType::TextOverflow(overflow) => {
+                let text_properties = TextProperties {
+                    overflow,
+                    ..*text_properties
+                };
+                for child in self.tree.child_iter(node) {
+                    self.render_element(&text_properties, rectangle, child);
+                }
+            }
+            ElementType::TextColor(color) => {
+                let text_properties = TextProperties {
+                    color,
+                    ..*text_properties
                 };
                 for child in self.tree.child_iter(node) {
                     self.render_element(&text_properties, rectangle, child);
@@ -273,6 +401,17 @@ impl<'a> Render<'a> {
                     self.render_element(&text_properties, rectangle, child);
                 }
             }
+            ElementType::PositionVerticalPercentage(percentage) => {
+                let rectangle = Rectangle::new(
+                    rectangle.x,
+                    rectangle.y + rectangle.height * percentage,
+                    rectangle.width,
+                    rectangle.height,
+                );
+                for child in self.tree.child_iter(node) {
+                    self.render_element(text_properties, rectangle, child);
+                }
+            }
             ElementType::PositionVerticalPixels(pixels) => {
                 let rectangle = Rectangle::new(
                     rectangle.x,
@@ -285,79 +424,59 @@ impl<'a> Render<'a> {
                 }
             }
             ElementType::Text(ref text) => {
-                // If no size is specified then scale the text to fit within the available space.
-                let text_size = text_properties.size;
-
                 if let Some(font) = text_properties.font {
-                    let fonts = [&self.fonts[font.0]];
-                    let text_style = fontdue::layout::TextStyle {
-                        text: &text,
-                        px: text_size,
-                        font_index: 0,
-                    };
+                    let text_layout = layout_text(
+                        &self.fonts[font.0],
+                        text,
+                        text_properties,
+                        None,
+                        Some(rectangle.width),
+                    );
+                    self.render_glyphs(font, text_properties.color, &text_layout.glyphs, rectangle);
+                }
 
-                    let layout_settings = fontdue::layout::LayoutSettings {
-                        ..fontdue::layout::LayoutSettings::default()
+                for child in self.tree.child_iter(node) {
+                    self.render_element(text_properties, rectangle, child);
+                }
+            }
+            ElementType::Icon(icon) => {
+                if let (Some(font), Some(icon)) = (self.icon_font, icon) {
+                    let size = text_properties.size;
+                    let icon_font = &self.fonts[font.0];
+                    let text_properties = TextProperties {
+                        line_height: 1.0,
+                        overflow: TextOverflow::Visible,
+                        ..*text_properties
                     };
+                    let text_layout =
+                        layout_text(icon_font, &icon.to_string(), &text_properties, None, None);
 
-                    let mut layout_output = Vec::new();
-                    let mut layout = fontdue::layout::Layout::new();
-
-                    layout.layout_horizontal(
-                        &fonts,
-                        &[&text_style],
-                        &layout_settings,
-                        &mut layout_output,
+                    // Center the glyph's advance and the line's ascent and descent in the square,
+                    // which is how icon fonts place glyphs in their design square.
+                    let advance = icon_font.metrics(icon, size).advance_width;
+                    let line_height = icon_font
+                        .horizontal_line_metrics(size)
+                        .map_or(size, |m| m.ascent - m.descent);
+                    let origin = Rectangle::new(
+                        rectangle.x + (size - advance) / 2.,
+                        rectangle.y + (size - line_height) / 2.,
+                        size,
+                        size,
                     );
-
-                    // It'd be good to have an option to trim overflow text if it's too long for the container.
-
-                    for c in layout_output {
-                        let texture_rectangle = self.drawing_info.texture.get_character(
-                            &self.fonts[font.0],
-                            c.key,
-                            c.width as u32,
-                            c.height as u32,
-                        );
-
-                        // If the character cannot be packed (it is too large or there's not space) then don't render it
-                        if let Some(texture_rectangle) = texture_rectangle {
-                            self.drawing_info
-                                .characters
-                                .push((self.drawing_info.drawables.len(), c.key));
-                            // Fontdue lays out relative to the upper left corner.
-                            // Fontdue's coordinate system is with 0, 0 in the lower left.
-                            let c_rectangle = (
-                                rectangle.x as f32 + c.x,
-                                rectangle.y + -c.y - texture_rectangle.height as f32, // Why is this shifting like this?
-                                texture_rectangle.width as f32,
-                                texture_rectangle.height as f32,
-                            );
-
-                            /*
-                            self.drawing_info.drawables.push(Drawable {
-                                texture_rectangle: (0., 0., 0., 0.), // This will be replaced later in the texture rectangle fixup step
-                                rectangle: c_rectangle,
-                                color: (1.0, 0.0, 0.0, 1.0),
-                                radiuses: None,
-                            });*/
-                            self.drawing_info.drawables.push(Drawable {
-                                texture_rectangle: (0., 0., 0., 0.), // This will be replaced later in the texture rectangle fixup step
-                                rectangle: c_rectangle,
-                                color: (1.0, 1.0, 1.0, 1.0),
-                                radiuses: None,
-                            });
-                        /*
-                        self.drawing_info.drawables.push(Drawable {
-                            texture_rectangle: (0., 0., 0., 0.), // This will be replaced later in the texture rectangle fixup step
-                            rectangle: c_rectangle,
-                            color: (1.0, 0.0, 0.0, 1.0),
-                            radiuses: None,
-                        });*/
-                        } else {
-                            println!("Text unrendered because texture atlas is full");
-                        }
-                    }
+                    self.render_glyphs(font, text_properties.color, &text_layout.glyphs, origin);
+                }
+            }
+            ElementType::Paragraph(ref text) => {
+                if let Some(font) = text_properties.font {
+                    // Wrap to the width calculated by the layout pass so that the lines match the measured height.
+                    let text_layout = layout_text(
+                        &self.fonts[font.0],
+                        text,
+                        text_properties,
+                        Some(element_rectangle.width),
+                        Some(rectangle.width),
+                    );
+                    self.render_glyphs(font, text_properties.color, &text_layout.glyphs, rectangle);
                 }
 
                 for child in self.tree.child_iter(node) {
@@ -373,4 +492,49 @@ impl<'a> Render<'a> {
             }
         }
     }
+
+    /// Pushes drawables for glyphs laid out relative to the upper left corner of the rectangle.
+    fn render_glyphs(
+        &mut self,
+        font: FontHandle,
+        color: (f32, f32, f32, f32),
+        glyphs: &[fontdue::layout::GlyphPosition],
+        rectangle: Rectangle,
+    ) {
+        for c in glyphs {
+            let texture_rectangle = self.drawing_info.texture.get_character(
+                &self.fonts[font.0],
+                c.key,
+                c.width as u32,
+                c.height as u32,
+            );
+
+            // If the character cannot be packed (it is too large or there's not space) then don't render it
+            if let Some(texture_rectangle) = texture_rectangle {
+                self.drawing_info
+                    .characters
+                    .push((self.drawing_info.drawables.len(), c.key));
+                // Fontdue lays out relative to the upper left corner.
+                // Fontdue's coordinate system is with 0, 0 in the lower left.
+                let c_rectangle = (
+                    rectangle.x + c.x,
+                    rectangle.y + -c.y - texture_rectangle.height as f32, // Why is this shifting like this?
+                    texture_rectangle.width as f32,
+                    texture_rectangle.height as f32,
+                );
+
+                self.push_drawable(Drawable {
+                    texture_rectangle: (0., 0., 0., 0.), // This will be replaced later in the texture rectangle fixup step
+                    rectangle: c_rectangle,
+                    color,
+                    radiuses: None,
+                    gradient: None,
+                    image: None,
+                    clip: None,
+                });
+            } else {
+                println!("Text unrendered because texture atlas is full");
+            }
+        }
+    }
 }
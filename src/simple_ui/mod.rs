@@ -12,6 +12,8 @@ pub struct SimpleUI {
     gl_context: GLContext,
     gl: glow::Context,
     gl_drawer: gl_drawer::GLDrawer,
+    /// Keys held down, as several keys like left and right Shift are the same key to the UI.
+    keys_held: Vec<Key>,
 }
 
 impl SimpleUI {
@@ -64,6 +66,7 @@ impl SimpleUI {
             window,
             gl,
             gl_drawer,
+            keys_held: Vec::new(),
         }
     }
 
@@ -83,16 +86,45 @@ impl SimpleUI {
                 self.ui.pointer_move(x as f32, y as f32);
                 self.window.request_redraw();
             }
-            Event::PointerDown { x, y, .. } => {
-                self.ui.pointer_down(x as f32, y as f32);
-                self.window.request_redraw();
+            Event::PointerDown { x, y, button, .. } => {
+                if let Some(button) = convert_pointer_button(button) {
+                    self.ui.pointer_down(x as f32, y as f32, button);
+                    self.window.request_redraw();
+                }
+            }
+            Event::PointerUp { x, y, button, .. } => {
+                if let Some(button) = convert_pointer_button(button) {
+                    self.ui.pointer_up(x as f32, y as f32, button);
+                    self.window.request_redraw();
+                }
             }
-            Event::PointerUp { x, y, .. } => {
-                self.ui.pointer_up(x as f32, y as f32);
+            Event::Scroll {
+                delta_x, delta_y, ..
+            } => {
+                self.ui.scroll(delta_x as f32, delta_y as f32);
                 self.window.request_redraw();
             }
-            Event::Scroll { delta_y, .. } => {
-                self.ui.scroll(delta_y as f32);
+            Event::KeyDown { key, .. } | Event::KeyRepeat { key, .. } => {
+                if !self.keys_held.contains(&key) {
+                    self.keys_held.push(key);
+                }
+                if let Some(key) = convert_key(key) {
+                    self.ui.key_down(key);
+                    self.window.request_redraw();
+                }
+            }
+            Event::KeyUp { key, .. } => {
+                self.keys_held.retain(|k| *k != key);
+                // Releasing one Shift key doesn't release Shift while the other is held.
+                let converted = convert_key(key);
+                let still_held = self.keys_held.iter().any(|k| convert_key(*k) == converted);
+                if let (Some(key), false) = (converted, still_held) {
+                    self.ui.key_up(key);
+                    self.window.request_redraw();
+                }
+            }
+            Event::CharacterReceived { character } => {
+                self.ui.character_input(character);
                 self.window.request_redraw();
             }
             _ => {}
@@ -127,3 +159,76 @@ impl SimpleUI {
         }
     }
 }
+
+/// Translate a kapp pointer button to a pointer button the UI understands.
+fn convert_pointer_button(button: PointerButton) -> Option<crate::input::PointerButton> {
+    use crate::input::PointerButton as B;
+    Some(match button {
+        PointerButton::Primary => B::Primary,
+        PointerButton::Secondary => B::Secondary,
+        PointerButton::Auxillary => B::Middle,
+        _ => return None,
+    })
+}
+
+/// Translate a kapp key to a key the UI understands.
+fn convert_key(key: Key) -> Option<crate::input::Key> {
+    use crate::input::Key as K;
+    Some(match key {
+        Key::A => K::A,
+        Key::B => K::B,
+        Key::C => K::C,
+        Key::D => K::D,
+        Key::E => K::E,
+        Key::F => K::F,
+        Key::G => K::G,
+        Key::H => K::H,
+        Key::I => K::I,
+        Key::J => K::J,
+        Key::K => K::K,
+        Key::L => K::L,
+        Key::M => K::M,
+        Key::N => K::N,
+        Key::O => K::O,
+        Key::P => K::P,
+        Key::Q => K::Q,
+        Key::R => K::R,
+        Key::S => K::S,
+        Key::T => K::T,
+        Key::U => K::U,
+        Key::V => K::V,
+        Key::W => K::W,
+        Key::X => K::X,
+        Key::Y => K::Y,
+        Key::Z => K::Z,
+        Key::Digit0 => K::Digit0,
+        Key::Digit1 => K::Digit1,
+        Key::Digit2 => K::Digit2,
+        Key::Digit3 => K::Digit3,
+        Key::Digit4 => K::Digit4,
+        Key::Digit5 => K::Digit5,
+        Key::Digit6 => K::Digit6,
+        Key::Digit7 => K::Digit7,
+        Key::Digit8 => K::Digit8,
+        Key::Digit9 => K::Digit9,
+        Key::Left => K::Left,
+        Key::Right => K::Right,
+        Key::Up => K::Up,
+        Key::Down => K::Down,
+        Key::Home => K::Home,
+        Key::End => K::End,
+        Key::PageUp => K::PageUp,
+        Key::PageDown => K::PageDown,
+        Key::Tab => K::Tab,
+        Key::Escape => K::Escape,
+        Key::Enter => K::Enter,
+        Key::Backspace => K::Backspace,
+        Key::Delete => K::Delete,
+        Key::Space => K::Space,
+        Key::LeftShift | Key::RightShift => K::Shift,
+        Key::LeftControl | Key::RightControl => K::Control,
+        Key::LeftAlt | Key::RightAlt => K::Alt,
+        Key::Meta => K::Meta,
+        _ => return None,
+    })
+}
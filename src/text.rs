@@ -0,0 +1,386 @@
+//! Text layout shared by the layout and render passes so that both agree on where glyphs go.
+use crate::ui::{TextAlign, TextOverflow, TextProperties};
+use fontdue::layout::GlyphPosition;
+
+const ELLIPSIS: char = '…';
+
+/// The output of laying out a piece of text.
+pub(crate) struct TextLayout {
+    /// Glyphs positioned relative to the upper left corner of the text.
+    /// Like fontdue the y axis points up.
+    pub(crate) glyphs: Vec<GlyphPosition>,
+    /// The width of the widest line.
+    pub(crate) width: f32,
+    /// The height of all lines.
+    pub(crate) height: f32,
+}
+
+/// A line of text after breaking.
+struct Line {
+    text: String,
+    /// The last line of a paragraph is not justified.
+    ends_paragraph: bool,
+}
+
+/// Lays out text with a line for each explicit newline.
+/// If a wrap width is specified lines are also wrapped at word boundaries to fit within it.
+/// The available width is used to align lines and to truncate them if overflow is set to ellipsis.
+pub(crate) fn layout_text(
+    font: &fontdue::Font,
+    text: &str,
+    text_properties: &TextProperties,
+    wrap_width: Option<f32>,
+    available_width: Option<f32>,
+) -> TextLayout {
+    let size = text_properties.size;
+    let line_height = font
+        .horizontal_line_metrics(size)
+        .map_or(size, |m| m.new_line_size)
+        * text_properties.line_height;
+
+    let mut lines = break_lines(font, text, size, wrap_width);
+    if let (TextOverflow::Ellipsis, Some(available_width)) =
+        (text_properties.overflow, available_width)
+    {
+        for line in &mut lines {
+            truncate_line(font, &mut line.text, size, available_width);
+        }
+    }
+
+    let fonts = [font];
+    let mut layout = fontdue::layout::Layout::new();
+    let mut glyphs = Vec::new();
+    let mut line_output = Vec::new();
+    let mut width: f32 = 0.;
+
+    for (i, line) in lines.iter().enumerate() {
+        let text_style = fontdue::layout::TextStyle {
+            text: &line.text,
+            px: size,
+            font_index: 0,
+        };
+        line_output.clear();
+        layout.layout_horizontal(
+            &fonts,
+            &[&text_style],
+            &fontdue::layout::LayoutSettings::default(),
+            &mut line_output,
+        );
+
+        let this_line_width = line_width(font, &line.text, size);
+        width = width.max(this_line_width);
+
+        // Lines that are too wide start at the left edge regardless of alignment.
+        let extra_space = available_width.map_or(0., |w| (w - this_line_width).max(0.));
+        let offset_x = match text_properties.align {
+            TextAlign::Left | TextAlign::Justify => 0.,
+            TextAlign::Center => extra_space / 2.,
+            TextAlign::Right => extra_space,
+        };
+
+        // Justified lines spread the extra space between their spaces.
+        let trimmed = line.text.trim_end();
+        let space_count = trimmed.chars().filter(|c| *c == ' ').count();
+        let space_width = if text_properties.align == TextAlign::Justify
+            && !line.ends_paragraph
+            && space_count > 0
+        {
+            extra_space / space_count as f32
+        } else {
+            0.
+        };
+
+        // Move each line down below the previous line.
+        let offset_y = i as f32 * line_height;
+        let mut characters = trimmed.chars();
+        let mut spaces_before = 0;
+        for mut glyph in line_output.drain(..) {
+            // Find the character for this glyph to count the spaces before it.
+            if space_width > 0. {
+                for c in &mut characters {
+                    if c == ' ' {
+                        spaces_before += 1;
+                    }
+                    if c == glyph.key.c {
+                        break;
+                    }
+                }
+            }
+            glyph.x += offset_x + spaces_before as f32 * space_width;
+            glyph.y -= offset_y;
+            glyphs.push(glyph);
+        }
+    }
+
+    TextLayout {
+        glyphs,
+        width,
+        height: lines.len() as f32 * line_height,
+    }
+}
+
+/// The width of a line, excluding trailing whitespace.
+fn line_width(font: &fontdue::Font, line: &str, size: f32) -> f32 {
+    line.trim_end()
+        .chars()
+        .map(|c| font.metrics(c, size).advance_width)
+        .sum()
+}
+
+/// Shortens the line and appends an ellipsis if it's wider than the max width.
+fn truncate_line(font: &fontdue::Font, line: &mut String, size: f32, max_width: f32) {
+    if line_width(font, line, size) <= max_width + 0.01 {
+        return;
+    }
+    let available_width = max_width - font.metrics(ELLIPSIS, size).advance_width;
+    let mut width = 0.;
+    let mut end = 0;
+    for (i, c) in line.char_indices() {
+        width += font.metrics(c, size).advance_width;
+        if width > available_width {
+            break;
+        }
+        end = i + c.len_utf8();
+    }
+    line.truncate(end);
+    line.truncate(line.trim_end().len());
+    line.push(ELLIPSIS);
+}
+
+/// Splits text on newlines and, if a max width is specified, at the last space that fits.
+/// Words too long to fit on a line by themselves are broken between characters.
+fn break_lines(font: &fontdue::Font, text: &str, size: f32, max_width: Option<f32>) -> Vec<Line> {
+    let mut lines = Vec::new();
+    let mut push_line = |text: &str, ends_paragraph: bool| {
+        lines.push(Line {
+            text: text.to_owned(),
+            ends_paragraph,
+        })
+    };
+
+    for hard_line in text.split('\n') {
+        let max_width = match max_width {
+            Some(max_width) => max_width,
+            None => {
+                push_line(hard_line, true);
+                continue;
+            }
+        };
+
+        let mut line_start = 0;
+        // The byte index after the last space and the width up to that point.
+        let mut last_break = None;
+        let mut width = 0.;
+        for (i, c) in hard_line.char_indices() {
+            let advance = font.metrics(c, size).advance_width;
+            if c == ' ' {
+                width += advance;
+                last_break = Some((i + 1, width));
+                continue;
+            }
+
+            // Only non-whitespace characters can overflow the line.
+            // A small tolerance is allowed for the widths calculated in a previous pass.
+            if width + advance > max_width + 0.01 && i > line_start {
+                if let Some((break_index, break_width)) = last_break {
+                    push_line(hard_line[line_start..break_index].trim_end(), false);
+                    line_start = break_index;
+                    width -= break_width;
+                } else {
+                    push_line(&hard_line[line_start..i], false);
+                    line_start = i;
+                    width = 0.;
+                }
+                last_break = None;
+            }
+            width += advance;
+        }
+        push_line(&hard_line[line_start..], true);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIZE: f32 = 20.;
+
+    fn font() -> fontdue::Font {
+        fontdue::Font::from_bytes(
+            include_bytes!("../resources/Inter-Medium.ttf") as &[u8],
+            fontdue::FontSettings::default(),
+        )
+        .unwrap()
+    }
+
+    fn texts(lines: &[Line]) -> Vec<&str> {
+        lines.iter().map(|line| line.text.as_str()).collect()
+    }
+
+    #[test]
+    fn break_lines_splits_on_newlines_only_without_max_width() {
+        let lines = break_lines(&font(), "one two\nthree", SIZE, None);
+        assert_eq!(texts(&lines), ["one two", "three"]);
+        assert!(lines.iter().all(|line| line.ends_paragraph));
+    }
+
+    #[test]
+    fn break_lines_wraps_at_last_space_that_fits() {
+        let font = font();
+        let max_width = line_width(&font, "one two three", SIZE) - 1.;
+        let lines = break_lines(&font, "one two three", SIZE, Some(max_width));
+        assert_eq!(texts(&lines), ["one two", "three"]);
+        assert!(!lines[0].ends_paragraph);
+        assert!(lines[1].ends_paragraph);
+    }
+
+    #[test]
+    fn break_lines_allows_widths_from_previous_pass() {
+        // Widths measured by the layout pass may be slightly off when rendering.
+        let font = font();
+        let max_width = line_width(&font, "one two", SIZE) - 0.005;
+        let lines = break_lines(&font, "one two", SIZE, Some(max_width));
+        assert_eq!(texts(&lines), ["one two"]);
+    }
+
+    #[test]
+    fn break_lines_breaks_long_words_between_characters() {
+        let font = font();
+        let max_width = line_width(&font, "mmm", SIZE);
+        let lines = break_lines(&font, "mmmmmmm", SIZE, Some(max_width));
+        assert_eq!(texts(&lines), ["mmm", "mmm", "m"]);
+    }
+
+    #[test]
+    fn break_lines_keeps_every_line_within_max_width() {
+        let font = font();
+        let text = "The quick brown fox jumps over the lazy dog.\nPack my box with five dozen liquor jugs.";
+        let max_width = line_width(&font, "The quick brown", SIZE);
+        let lines = break_lines(&font, text, SIZE, Some(max_width));
+        assert!(lines.len() > 2);
+        for line in &lines {
+            assert!(line_width(&font, &line.text, SIZE) <= max_width + 0.01);
+            assert_eq!(line.text, line.text.trim_end());
+        }
+        assert_eq!(lines.iter().filter(|line| line.ends_paragraph).count(), 2);
+    }
+
+    #[test]
+    fn layout_text_is_as_tall_as_its_lines() {
+        let font = font();
+        let text_properties = TextProperties {
+            size: SIZE,
+            line_height: 1.5,
+            ..TextProperties::new()
+        };
+        let line_height = font
+            .horizontal_line_metrics(SIZE)
+            .map_or(SIZE, |m| m.new_line_size)
+            * 1.5;
+        let wrap_width = line_width(&font, "one two", SIZE);
+        let text_layout = layout_text(
+            &font,
+            "one two three\nfour",
+            &text_properties,
+            Some(wrap_width),
+            None,
+        );
+        assert_eq!(text_layout.height, 3. * line_height);
+        assert_eq!(text_layout.width, wrap_width);
+    }
+
+    #[test]
+    fn truncate_line_leaves_lines_that_fit() {
+        let font = font();
+        let mut line = String::from("short");
+        let max_width = line_width(&font, &line, SIZE) - 0.005;
+        truncate_line(&font, &mut line, SIZE, max_width);
+        assert_eq!(line, "short");
+    }
+
+    #[test]
+    fn truncate_line_ends_with_ellipsis_within_max_width() {
+        let font = font();
+        let mut line = String::from("a line that is too long to fit");
+        let max_width = line_width(&font, "a line that", SIZE);
+        truncate_line(&font, &mut line, SIZE, max_width);
+        assert!(line.ends_with(ELLIPSIS));
+        assert!(line_width(&font, &line, SIZE) <= max_width + 0.01);
+        let kept = line.trim_end_matches(ELLIPSIS);
+        assert!(!kept.is_empty());
+        assert!("a line that is too long to fit".starts_with(kept));
+        assert_eq!(kept, kept.trim_end());
+    }
+
+    /// Lays out the text and returns the x position of each glyph.
+    fn glyph_xs(text: &str, align: TextAlign, wrap_width: f32) -> Vec<f32> {
+        let text_properties = TextProperties {
+            size: SIZE,
+            align,
+            ..TextProperties::new()
+        };
+        layout_text(
+            &font(),
+            text,
+            &text_properties,
+            Some(wrap_width),
+            Some(wrap_width),
+        )
+        .glyphs
+        .iter()
+        .map(|glyph| glyph.x)
+        .collect()
+    }
+
+    #[test]
+    fn layout_text_centers_and_right_aligns_in_available_width() {
+        let font = font();
+        let available_width = line_width(&font, "centered", SIZE) + 40.;
+        let left = glyph_xs("centered", TextAlign::Left, available_width);
+        let center = glyph_xs("centered", TextAlign::Center, available_width);
+        let right = glyph_xs("centered", TextAlign::Right, available_width);
+        for i in 0..left.len() {
+            assert!((center[i] - left[i] - 20.).abs() < 0.01);
+            assert!((right[i] - left[i] - 40.).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn layout_text_justifies_all_but_the_last_line_of_a_paragraph() {
+        let font = font();
+        let text = "one two three";
+        let wrap_width = line_width(&font, text, SIZE) - 1.;
+        let extra_space = wrap_width - line_width(&font, "one two", SIZE);
+        let first_line_glyphs = glyph_xs("one two", TextAlign::Left, wrap_width).len();
+
+        let left = glyph_xs(text, TextAlign::Left, wrap_width);
+        let justified = glyph_xs(text, TextAlign::Justify, wrap_width);
+        assert_eq!(left.len(), justified.len());
+        // Glyphs before the space stay put and those after it move by the extra space.
+        assert_eq!(justified[0], left[0]);
+        let last = first_line_glyphs - 1;
+        assert!((justified[last] - left[last] - extra_space).abs() < 0.01);
+        // The last line of the paragraph isn't stretched.
+        assert_eq!(&justified[first_line_glyphs..], &left[first_line_glyphs..]);
+    }
+
+    #[test]
+    fn layout_text_with_ellipsis_fits_available_width() {
+        let font = font();
+        let text_properties = TextProperties {
+            size: SIZE,
+            overflow: TextOverflow::Ellipsis,
+            ..TextProperties::new()
+        };
+        let available_width = line_width(&font, "a long", SIZE);
+        let text_layout = layout_text(
+            &font,
+            "a long line of text",
+            &text_properties,
+            None,
+            Some(available_width),
+        );
+        assert!(text_layout.width <= available_width + 0.01);
+    }
+}
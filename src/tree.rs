@@ -116,6 +116,22 @@ impl Tree {
         self.available_nodes.push(node_handle);
     }
 
+    pub fn parent(&self, node: NodeHandle) -> Option<NodeHandle> {
+        self.node(node).parent
+    }
+
+    /// Returns true if the node is the ancestor or is a descendent of the ancestor.
+    pub fn is_descendent(&self, node: NodeHandle, ancestor: NodeHandle) -> bool {
+        let mut node = Some(node);
+        while let Some(n) = node {
+            if n == ancestor {
+                return true;
+            }
+            node = self.parent(n);
+        }
+        false
+    }
+
     pub fn child_iter(&self, node: NodeHandle) -> TreeSiblingIterator {
         TreeSiblingIterator {
             node: self.nodes[node.0].first_child,
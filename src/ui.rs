@@ -1,6 +1,7 @@
 use fontdue;
 
 use crate::drawing_info::*;
+use crate::input::{Key, Modifiers, PointerButton};
 use crate::layout::Layout;
 use crate::rectangle::Rectangle;
 use crate::render::Render;
@@ -35,9 +36,37 @@ pub type ElementHandle = NodeHandle;
 #[derive(Copy, Clone, Debug)]
 pub struct FontHandle(pub(crate) usize);
 
+#[derive(Copy, Clone, Debug)]
+pub struct ImageHandle(pub(crate) usize);
+
+/// How lines of text are positioned horizontally within their rectangle.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TextAlign {
+    Left,
+    Center,
+    Right,
+    /// Stretches the spaces of every line but the last line of a paragraph to fill the width.
+    Justify,
+}
+
+/// What happens to text that is wider than the available space.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TextOverflow {
+    /// The text draws outside of its rectangle.
+    Visible,
+    /// The text is shortened and ends with an ellipsis.
+    Ellipsis,
+}
+
+#[derive(Copy, Clone)]
 pub struct TextProperties {
     pub size: f32,
     pub font: Option<FontHandle>,
+    pub align: TextAlign,
+    /// A multiplier of the font's line height.
+    pub line_height: f32,
+    pub overflow: TextOverflow,
+    pub color: (f32, f32, f32, f32),
 }
 
 impl TextProperties {
@@ -47,6 +76,10 @@ impl TextProperties {
             // 17 is the recommended size for buttons on iOS, so a bit arbitrary.
             size: 34.,
             font: None,
+            align: TextAlign::Left,
+            line_height: 1.0,
+            overflow: TextOverflow::Visible,
+            color: (1.0, 1.0, 1.0, 1.0),
         }
     }
 }
@@ -56,12 +89,23 @@ pub enum ElementType {
     Fill((f32, f32, f32, f32)),
     /// A rounded fill. The first f32s are corner radiuses, the second are colors.
     RoundedFill((f32, f32, f32, f32), (f32, f32, f32, f32)),
+    /// A fill that blends between colors for the top left, top right, bottom right and
+    /// bottom left corners.
+    Gradient([(f32, f32, f32, f32); 4]),
+    /// An image scaled to fit the available space while keeping its aspect ratio.
+    /// Its size is the image's size in pixels.
+    Image(ImageHandle),
+    /// A glyph from the icon font centered in a square as wide as the text size.
+    /// Unknown icon names are `None` and leave the square empty.
+    Icon(Option<char>),
     /// A container that accepts a single element and constrains its width
     Width(f32),
     /// A container that accepts a single element and constrains its width by a percentage
     WidthPercentage(f32),
     /// A container that accepts a single element and constrains its height
     Height(f32),
+    /// A container that accepts a single element and constrains its height by a percentage
+    HeightPercentage(f32),
     /// A container that accepts a single element and pads its width and height
     Padding(f32, f32),
     /// Rows lay out multiple elements in a row.
@@ -73,6 +117,8 @@ pub enum ElementType {
     PositionHorizontalPercentage(f32),
     /// Moves an element towards the horizontal end of the parent.
     PositionHorizontalPixels(f32),
+    /// Moves an element by a percentage of the parent towards the vertical end of the parent.
+    PositionVerticalPercentage(f32),
     /// Moves an element towards the vertical end of the parent.
     PositionVerticalPixels(f32),
     /// Columns lay out multiple elements in a column.
@@ -80,11 +126,23 @@ pub enum ElementType {
     Column(f32),
     /// Unstyled text
     Text(String),
+    /// Text that wraps at word boundaries to fit within the available width.
+    Paragraph(String),
     /// Specify text size for dependent elements,
     /// If text size is none then the text will slace to fit the space
     TextSize(f32),
     /// Specifies font to use for children. Defaults to 'None'.
     Font(FontHandle),
+    /// Specifies how text is aligned for children. Defaults to `TextAlign::Left`.
+    TextAlign(TextAlign),
+    /// Specifies a multiplier of the font's line height for children. Defaults to 1.0.
+    LineHeight(f32),
+    /// Specifies what happens to text that doesn't fit. Defaults to `TextOverflow::Visible`.
+    TextOverflow(TextOverflow),
+    /// Specifies the color of text for children. Defaults to white.
+    TextColor((f32, f32, f32, f32)),
+    /// Children are not drawn outside of this element's rectangle.
+    Clip,
     /// Centers children horizontally and vertically in available space.
     Center(bool, bool),
     /// Always takes up maximum available space
@@ -107,7 +165,18 @@ pub enum ElementType {
 pub struct Element {
     pub element_type: ElementType,
     pub rectangle: Rectangle,
+    /// The area the element can be seen in, if a parent clips it.
+    pub clip: Option<Rectangle>,
     pub widget: Option<usize>,
+    /// The widget ID used for focus if this element can be focused.
+    pub focus_id: Option<u64>,
+}
+
+impl Element {
+    /// Returns true if the point is within the part of the element that isn't clipped.
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        self.rectangle.contains(x, y) && self.clip.is_none_or(|clip| clip.contains(x, y))
+    }
 }
 
 use std::collections::HashMap;
@@ -115,6 +184,16 @@ use std::collections::HashMap;
 pub struct UITree {
     tree: Tree,
     root: NodeHandle,
+    /// Elements in the modal layer are drawn above the root.
+    /// While the modal layer has elements the root doesn't receive input.
+    modal_root: NodeHandle,
+    /// How many popups were added before the modal layer, which are beneath it.
+    popups_before_modal: usize,
+    /// Elements in the overlay are laid out and drawn last, above everything else.
+    overlay_root: NodeHandle,
+    /// Popups in the overlay, in the order they were added.
+    /// While the pointer is over a popup only the popup receives pointer input.
+    popups: Vec<NodeHandle>,
     elements: Vec<Element>,
 }
 
@@ -124,8 +203,14 @@ impl UITree {
             tree: Tree::new(),
             elements: Vec::new(),
             root: NodeHandle(0),
+            modal_root: NodeHandle(0),
+            popups_before_modal: 0,
+            overlay_root: NodeHandle(0),
+            popups: Vec::new(),
         };
-        ui_tree.add(ElementType::Expander, None);
+        ui_tree.root = ui_tree.add(ElementType::Expander, None);
+        ui_tree.modal_root = ui_tree.add(ElementType::Expander, None);
+        ui_tree.overlay_root = ui_tree.add(ElementType::Expander, None);
         ui_tree
     }
 
@@ -138,7 +223,9 @@ impl UITree {
         let element = Element {
             element_type,
             rectangle: Rectangle::zero(),
+            clip: None,
             widget: None,
+            focus_id: None,
         };
         // If the tree has allocated a new index, push the element there.
         if new_handle.0 >= self.elements.len() {
@@ -151,7 +238,78 @@ impl UITree {
 
     pub fn reset(&mut self) {
         self.tree.remove(self.root);
+        self.tree.remove(self.modal_root);
+        self.tree.remove(self.overlay_root);
+        self.popups.clear();
+        self.popups_before_modal = 0;
         self.root = self.add(ElementType::Expander, None);
+        self.modal_root = self.add(ElementType::Expander, None);
+        self.overlay_root = self.add(ElementType::Expander, None);
+    }
+
+    /// Returns the IDs of focusable elements in the order they appear in the tree.
+    fn focus_order(&self) -> Vec<u64> {
+        let mut order = Vec::new();
+        // Widgets beneath a modal can't be focused.
+        if !self.has_modal() {
+            self.collect_focusable(self.root, &mut order);
+        }
+        self.collect_focusable(self.modal_root, &mut order);
+        self.collect_focusable(self.overlay_root, &mut order);
+        order
+    }
+
+    /// Returns true if anything was added to the modal layer.
+    fn has_modal(&self) -> bool {
+        self.tree.child_iter(self.modal_root).next().is_some()
+    }
+
+    /// Returns true if the point is within a focusable element that's the node or beneath it.
+    fn focusable_at(&self, node: NodeHandle, x: f32, y: f32) -> bool {
+        let element = &self.elements[node.0];
+        (element.focus_id.is_some() && element.contains(x, y))
+            || self
+                .tree
+                .child_iter(node)
+                .any(|child| self.focusable_at(child, x, y))
+    }
+
+    /// Returns true if the node is beneath the modal layer.
+    /// That's the root and popups added before the modal layer had elements.
+    fn beneath_modal_layer(&self, node: NodeHandle) -> bool {
+        let popups_before_modal = if self.has_modal() {
+            self.popups_before_modal
+        } else {
+            self.popups.len()
+        };
+        self.tree.is_descendent(node, self.root)
+            || self.popups[..popups_before_modal]
+                .iter()
+                .any(|popup| self.tree.is_descendent(node, *popup))
+    }
+
+    /// The last added popup that contains the point.
+    /// Popups beneath the modal layer are ignored while it has elements.
+    fn popup_at(&self, x: f32, y: f32) -> Option<NodeHandle> {
+        let first = if self.has_modal() {
+            self.popups_before_modal
+        } else {
+            0
+        };
+        self.popups[first..]
+            .iter()
+            .rev()
+            .copied()
+            .find(|popup| self.elements[popup.0].rectangle.contains(x, y))
+    }
+
+    fn collect_focusable(&self, node: NodeHandle, order: &mut Vec<u64>) {
+        if let Some(id) = self.elements[node.0].focus_id {
+            order.push(id);
+        }
+        for child in self.tree.child_iter(node) {
+            self.collect_focusable(child, order);
+        }
     }
 }
 
@@ -165,13 +323,38 @@ pub struct UI {
     height: f32,
     drawing_info: DrawingInfo,
     fonts: Vec<fontdue::Font>,
+    /// The font `ElementType::Icon` draws glyphs from.
+    icon_font: Option<FontHandle>,
     pointer_x: f32,
     pointer_y: f32,
-    pointer_down: bool,
-    pointer_up: bool,
-    scroll_delta: f32,
+    /// Pointer buttons pressed since the last frame.
+    pointer_down: Vec<PointerButton>,
+    /// Pointer buttons released since the last frame.
+    pointer_up: Vec<PointerButton>,
+    scroll_delta: (f32, f32),
+    /// Keys currently held down.
+    keys_held: Vec<Key>,
+    /// Keys pressed (or repeated) since the last frame.
+    keys_pressed: Vec<Key>,
+    /// Keys pressed since the last frame, not counting repeats.
+    keys_pressed_without_repeat: Vec<Key>,
+    /// Keys released since the last frame.
+    keys_released: Vec<Key>,
+    /// Characters typed since the last frame.
+    characters: String,
+    /// The ID of the widget that receives keyboard input.
+    focused: Option<u64>,
     last_animation_timestamp: Option<std::time::Instant>,
+    /// Milliseconds since the previous frame, used to advance animations.
+    animation_delta: f32,
     animation_frame_requested: bool,
+    /// A widget asked for popups to close during the last frame.
+    close_popups_requested: bool,
+    /// Popups should close during this frame.
+    closing_popups: bool,
+    /// A widget claimed the current press, such as a scroll view that started scrolling.
+    /// Other widgets ignore the press until it's released.
+    pointer_captured: bool,
     widgets: Vec<Option<Box<dyn Widget>>>,
     widget_id_to_index: HashMap<u64, usize>,
 }
@@ -187,17 +370,29 @@ impl UI {
                 canvas_width: 0.0,
                 canvas_height: 0.0,
                 drawables: Vec::new(),
+                images: Vec::new(),
                 texture: Texture::new(2048),
                 characters: Vec::new(),
             },
             fonts: Vec::new(),
+            icon_font: None,
             pointer_x: 0.0,
             pointer_y: 0.0,
-            pointer_down: false,
-            pointer_up: false,
-            scroll_delta: 0.,
+            pointer_down: Vec::new(),
+            pointer_up: Vec::new(),
+            scroll_delta: (0., 0.),
+            keys_held: Vec::new(),
+            keys_pressed: Vec::new(),
+            keys_pressed_without_repeat: Vec::new(),
+            keys_released: Vec::new(),
+            characters: String::new(),
+            focused: None,
             last_animation_timestamp: None,
+            animation_delta: 0.,
             animation_frame_requested: false,
+            close_popups_requested: false,
+            closing_popups: false,
+            pointer_captured: false,
             widgets: Vec::new(),
             widget_id_to_index: HashMap::new(),
         };
@@ -211,9 +406,40 @@ impl UI {
         FontHandle(self.fonts.len() - 1)
     }
 
+    /// Add an image from RGBA bytes, four per pixel, row by row from the top left.
+    /// Panics if the image is empty or the data isn't the right length.
+    pub fn image_from_rgba(&mut self, width: u32, height: u32, bytes: &[u8]) -> ImageHandle {
+        assert!(width > 0 && height > 0, "Images must not be empty");
+        let length = (width as usize)
+            .checked_mul(height as usize)
+            .and_then(|pixels| pixels.checked_mul(4))
+            .expect("Image is too large");
+        assert_eq!(
+            bytes.len(),
+            length,
+            "Image data must have four bytes per pixel"
+        );
+        self.drawing_info.images.push(Image {
+            width,
+            height,
+            data: bytes.to_vec(),
+        });
+        ImageHandle(self.drawing_info.images.len() - 1)
+    }
+
+    /// Load a font that icons are drawn from, such as the bundled Material Icons font.
+    pub fn icon_font_from_bytes(&mut self, bytes: &[u8]) -> FontHandle {
+        let font = self.font_from_bytes(bytes);
+        self.icon_font = Some(font);
+        font
+    }
+
     pub fn edit<'a>(&'a mut self) -> UIBuilder {
         std::mem::swap(&mut self.old_ui_tree, &mut self.current_ui_tree);
         self.current_ui_tree.reset();
+        self.update_focus();
+        self.update_animation_delta();
+        self.closing_popups = std::mem::replace(&mut self.close_popups_requested, false);
         let root = self.current_ui_tree.root;
         UIBuilder {
             ui: Rc::new(RefCell::new(self)),
@@ -221,6 +447,56 @@ impl UI {
         }
     }
 
+    /// Moves focus based on the input since the last frame.
+    /// Widgets that were clicked request focus again while they're built.
+    fn update_focus(&mut self) {
+        let focus_order = self.old_ui_tree.focus_order();
+
+        // Clicking anywhere removes focus.
+        // Other buttons don't, so right-clicking a text field for a context menu keeps it focused.
+        if self.pointer_down.contains(&PointerButton::Primary) {
+            self.focused = None;
+        }
+
+        // The focused widget is no longer in the UI.
+        if let Some(focused) = self.focused {
+            if !focus_order.contains(&focused) {
+                self.focused = None;
+            }
+        }
+
+        // Tab moves to the next focusable widget and Shift-Tab to the previous.
+        if self.keys_pressed.contains(&Key::Tab) && !focus_order.is_empty() {
+            let len = focus_order.len();
+            let index = self
+                .focused
+                .and_then(|focused| focus_order.iter().position(|id| *id == focused));
+            let next = match (index, self.modifiers().shift) {
+                (Some(i), false) => (i + 1) % len,
+                (Some(i), true) => (i + len - 1) % len,
+                (None, false) => 0,
+                (None, true) => len - 1,
+            };
+            self.focused = Some(focus_order[next]);
+        }
+    }
+
+    fn update_animation_delta(&mut self) {
+        // Widgets request another frame while building if they're still animating.
+        self.animation_frame_requested = false;
+        let elapsed = if let Some(last_time_stamp) = self.last_animation_timestamp {
+            last_time_stamp.elapsed().as_secs_f32() * 1000.
+        } else {
+            0.
+        };
+
+        // Cap animation frames at 33 ms (30 fps) deltas.
+        // This means that at lower framerates things will animate slower.
+        // This also means that after a long period of time the animation will not jump forward.
+        self.animation_delta = elapsed.min(33.);
+        self.last_animation_timestamp = Some(std::time::Instant::now());
+    }
+
     pub fn resize(&mut self, width: f32, height: f32) {
         self.width = width;
         self.height = height;
@@ -234,6 +510,7 @@ impl UI {
         // Calculate the sizes for various elements.
         let mut layout = Layout {
             fonts: &self.fonts,
+            images: &self.drawing_info.images,
             tree: &self.current_ui_tree.tree,
             elements: &mut self.current_ui_tree.elements,
         };
@@ -244,6 +521,16 @@ impl UI {
             &text_properties,
             self.current_ui_tree.root,
         );
+        layout.layout(
+            (self.width, self.height),
+            &text_properties,
+            self.current_ui_tree.modal_root,
+        );
+        layout.layout(
+            (self.width, self.height),
+            &text_properties,
+            self.current_ui_tree.overlay_root,
+        );
 
         self.drawing_info.drawables.clear();
         self.drawing_info.characters.clear();
@@ -251,10 +538,12 @@ impl UI {
         // Then render the final outputs based on the previously calculated sizes.
         let mut render = Render {
             fonts: &self.fonts,
+            icon_font: self.icon_font,
             tree: &self.current_ui_tree.tree,
             elements: &mut self.current_ui_tree.elements,
             drawing_info: &mut self.drawing_info,
             widgets: &mut self.widgets,
+            clip_stack: Vec::new(),
         };
 
         render.render_element(
@@ -262,14 +551,33 @@ impl UI {
             Rectangle::new(0., 0., self.width, self.height),
             self.current_ui_tree.root,
         );
+        render.render_element(
+            &text_properties,
+            Rectangle::new(0., 0., self.width, self.height),
+            self.current_ui_tree.modal_root,
+        );
+        // The overlay is drawn last so that it's above everything else.
+        render.render_element(
+            &text_properties,
+            Rectangle::new(0., 0., self.width, self.height),
+            self.current_ui_tree.overlay_root,
+        );
 
         //println!("Time: {:?}", now.elapsed().as_secs_f32());
         self.drawing_info.canvas_width = self.width;
         self.drawing_info.canvas_height = self.height;
 
-        self.pointer_down = false;
-        self.pointer_up = false;
-        self.scroll_delta = 0.;
+        // A claimed press ends when it's released.
+        if self.pointer_up.contains(&PointerButton::Primary) {
+            self.pointer_captured = false;
+        }
+        self.pointer_down.clear();
+        self.pointer_up.clear();
+        self.scroll_delta = (0., 0.);
+        self.keys_pressed.clear();
+        self.keys_pressed_without_repeat.clear();
+        self.keys_released.clear();
+        self.characters.clear();
 
         // self.drawing_info.texture.repack();
         self.drawing_info.fix_character_rectangles();
@@ -284,40 +592,53 @@ impl UI {
         self.pointer_y = y;
     }
 
-    pub fn pointer_down(&mut self, x: f32, y: f32) {
+    pub fn pointer_down(&mut self, x: f32, y: f32, button: PointerButton) {
         self.pointer_x = x;
         self.pointer_y = y;
-        self.pointer_down = true;
+        self.pointer_down.push(button);
     }
 
-    pub fn pointer_up(&mut self, x: f32, y: f32) {
+    pub fn pointer_up(&mut self, x: f32, y: f32, button: PointerButton) {
         self.pointer_x = x;
         self.pointer_y = y;
-        self.pointer_up = true;
+        self.pointer_up.push(button);
     }
 
-    pub fn scroll(&mut self, delta: f32) {
-        self.scroll_delta = delta;
+    pub fn scroll(&mut self, delta_x: f32, delta_y: f32) {
+        self.scroll_delta = (delta_x, delta_y);
     }
 
-    /*
-    pub fn animate(&mut self, widget: &mut impl Widget) {
-        self.animation_frame_requested = false;
-        let elapsed = if let Some(last_time_stamp) = self.last_animation_timestamp {
-            last_time_stamp.elapsed().as_secs_f32() * 1000.
-        } else {
-            0.
-        };
+    /// Press a key. Key repeats should also be passed in here.
+    pub fn key_down(&mut self, key: Key) {
+        // A key that's already held is repeating.
+        if !self.keys_held.contains(&key) {
+            self.keys_held.push(key);
+            self.keys_pressed_without_repeat.push(key);
+        }
+        self.keys_pressed.push(key);
+    }
 
-        // Cap animation frames at 33 ms (30 fps) deltas.
-        // This means that at lower framerates things will animate slower.
-        // This also means that after a long period of time the animation will jump forward.
-        // This probably requires a better solution.
-        let elapsed = elapsed.min(33.);
-        widget.event(self, UIEvent::AnimationFrame(elapsed));
-        self.last_animation_timestamp = Some(std::time::Instant::now());
+    pub fn key_up(&mut self, key: Key) {
+        self.keys_held.retain(|k| *k != key);
+        self.keys_released.push(key);
+    }
+
+    /// Input a typed character.
+    /// Control characters are ignored, editing keys should be passed to `key_down` instead.
+    pub fn character_input(&mut self, character: char) {
+        if !character.is_control() {
+            self.characters.push(character);
+        }
+    }
+
+    pub fn modifiers(&self) -> Modifiers {
+        Modifiers {
+            shift: self.keys_held.contains(&Key::Shift),
+            control: self.keys_held.contains(&Key::Control),
+            alt: self.keys_held.contains(&Key::Alt),
+            meta: self.keys_held.contains(&Key::Meta),
+        }
     }
-    */
 
     pub fn pointer_position(&self) -> (f32, f32) {
         (self.pointer_x, self.pointer_y)
@@ -359,6 +680,52 @@ impl<'a> UIBuilder<'a> {
         self.parent.unwrap()
     }
 
+    /// Elements added to the modal layer are drawn above the rest of the UI, which stops
+    /// receiving input while the modal layer has elements.
+    /// Popups that were already open are beneath the modal layer too.
+    /// The modal layer covers the whole window.
+    pub fn modal(&self) -> Self {
+        let modal_root = {
+            let mut ui = self.ui.borrow_mut();
+            let tree = &mut ui.current_ui_tree;
+            if !tree.has_modal() {
+                tree.popups_before_modal = tree.popups.len();
+            }
+            tree.modal_root
+        };
+        UIBuilder {
+            ui: self.ui.clone(),
+            parent: Some(modal_root),
+        }
+    }
+
+    /// Elements added to the overlay are drawn above the rest of the UI.
+    /// The overlay covers the whole window.
+    pub fn overlay(&self) -> Self {
+        let overlay_root = self.ui.borrow().current_ui_tree.overlay_root;
+        UIBuilder {
+            ui: self.ui.clone(),
+            parent: Some(overlay_root),
+        }
+    }
+
+    /// A popup is the size of its children and is drawn above the rest of the UI
+    /// at a position relative to the window.
+    /// While the pointer is over a popup elements beneath it do not receive pointer input.
+    pub fn popup(&self, x: f32, y: f32) -> Self {
+        let popup = self
+            .overlay()
+            .position_horizontal_pixels(x)
+            .position_vertical_pixels(y)
+            .fit();
+        self.ui
+            .borrow_mut()
+            .current_ui_tree
+            .popups
+            .push(popup.handle());
+        popup
+    }
+
     pub fn row(&self) -> Self {
         self.add(ElementType::Row(0.))
     }
@@ -428,6 +795,11 @@ impl<'a> UIBuilder<'a> {
         self.add(ElementType::Height(height_pixels))
     }
 
+    /// Percentage of parent
+    pub fn height_percentage(&self, height_percentage: f32) -> Self {
+        self.add(ElementType::HeightPercentage(height_percentage))
+    }
+
     /// Draw a rectangle that fills the entire available space
     pub fn fill(&self, color: (f32, f32, f32, f32)) -> Self {
         self.add(ElementType::Fill(color))
@@ -441,6 +813,23 @@ impl<'a> UIBuilder<'a> {
         ))
     }
 
+    /// Draw a rectangle that fills the entire available space, blending between colors
+    /// for the top left, top right, bottom right and bottom left corners.
+    pub fn gradient(&self, corner_colors: [(f32, f32, f32, f32); 4]) -> Self {
+        self.add(ElementType::Gradient(corner_colors))
+    }
+
+    /// Draw an image scaled to fit the available space while keeping its aspect ratio.
+    /// Use `fit` to draw the image at its own size or `scale_to_fit` to fill the space.
+    pub fn image(&self, image: ImageHandle) -> Self {
+        self.add(ElementType::Image(image))
+    }
+
+    /// Prevents children from drawing outside of the available space.
+    pub fn clip(&self) -> Self {
+        self.add(ElementType::Clip)
+    }
+
     pub fn center(&self) -> Self {
         self.add(ElementType::Center(true, true))
     }
@@ -457,6 +846,11 @@ impl<'a> UIBuilder<'a> {
         self.add(ElementType::Text(text.to_owned()))
     }
 
+    /// Text that wraps to fit the available width.
+    pub fn paragraph(&self, text: &str) -> Self {
+        self.add(ElementType::Paragraph(text.to_owned()))
+    }
+
     pub fn text_size(&self, size: f32) -> Self {
         self.add(ElementType::TextSize(size))
     }
@@ -465,6 +859,36 @@ impl<'a> UIBuilder<'a> {
         self.add(ElementType::Font(font))
     }
 
+    /// Draw an icon from the font loaded with `UI::icon_font_from_bytes`.
+    /// `name_or_codepoint` is a Material icon name, like "home", or a single character.
+    /// The icon is a square as wide as the text size, which is left empty for unknown names.
+    pub fn icon(&self, name_or_codepoint: &str) -> Self {
+        let mut characters = name_or_codepoint.chars();
+        let icon = match (characters.next(), characters.next()) {
+            (Some(c), None) => Some(c),
+            _ => crate::widgets::material_icon(name_or_codepoint),
+        };
+        self.add(ElementType::Icon(icon))
+    }
+
+    pub fn text_color(&self, color: (f32, f32, f32, f32)) -> Self {
+        self.add(ElementType::TextColor(color))
+    }
+
+    pub fn text_align(&self, align: TextAlign) -> Self {
+        self.add(ElementType::TextAlign(align))
+    }
+
+    /// Multiplies the spacing between lines of text.
+    pub fn line_height(&self, line_height: f32) -> Self {
+        self.add(ElementType::LineHeight(line_height))
+    }
+
+    /// Shortens text that is too wide and ends it with an ellipsis.
+    pub fn ellipsis(&self) -> Self {
+        self.add(ElementType::TextOverflow(TextOverflow::Ellipsis))
+    }
+
     /// Fits to children but can grow larger than the parent.
     pub fn fit(&self) -> Self {
         self.add(ElementType::Fit)
@@ -483,6 +907,10 @@ impl<'a> UIBuilder<'a> {
         self.add(ElementType::PositionHorizontalPixels(pixels))
     }
 
+    pub fn position_vertical_percentage(&self, percentage: f32) -> Self {
+        self.add(ElementType::PositionVerticalPercentage(percentage))
+    }
+
     pub fn position_vertical_pixels(&self, pixels: f32) -> Self {
         self.add(ElementType::PositionVerticalPixels(pixels))
     }
@@ -525,29 +953,198 @@ impl<'a> UIBuilder<'a> {
         }
     }
 
+    /// Allows the element to be focused with Tab.
+    /// The ID should be the same ID used for the widget.
+    pub fn focusable(&self, id: u64) {
+        let mut ui = self.ui.borrow_mut();
+        let handle = self.handle();
+        ui.current_ui_tree.elements[handle.0].focus_id = Some(id);
+    }
+
+    /// Focus the widget with the ID so that it receives keyboard input.
+    pub fn request_focus(&self, id: u64) {
+        self.ui.borrow_mut().focused = Some(id);
+    }
+
+    /// Remove focus from any widget that currently has it.
+    pub fn clear_focus(&self) {
+        self.ui.borrow_mut().focused = None;
+    }
+
+    pub fn is_focused(&self, id: u64) -> bool {
+        self.ui.borrow().focused == Some(id)
+    }
+
+    /// The width and height of the window.
+    pub fn window_size(&self) -> (f32, f32) {
+        let ui = self.ui.borrow();
+        (ui.width, ui.height)
+    }
+
     /// Code to query about input state.
     pub fn pointer_position(&self) -> (f32, f32) {
         let ui = self.ui.borrow();
         (ui.pointer_x, ui.pointer_y)
     }
 
-    pub fn scroll_delta(&self) -> f32 {
+    /// Milliseconds since the previous frame, capped to avoid large jumps in animations.
+    pub fn animation_delta(&self) -> f32 {
+        self.ui.borrow().animation_delta
+    }
+
+    /// Request that another frame is drawn, even if there is no user input.
+    /// Animations should call this every frame until they're finished.
+    pub fn request_animation_frame(&self) {
+        self.ui.borrow_mut().request_animation_frame();
+    }
+
+    /// Returns true if this builder shouldn't receive input because of a modal.
+    /// Input queries return nothing beneath a modal shown last frame or added this frame.
+    /// A modal doesn't receive input in the frame it appears, so the input that opened it
+    /// isn't handled twice.
+    fn beneath_modal(&self) -> bool {
+        let ui = self.ui.borrow();
+        let tree = &ui.current_ui_tree;
+        let parent = match self.parent {
+            Some(parent) => parent,
+            None => return false,
+        };
+        if tree.tree.is_descendent(parent, tree.modal_root) {
+            return !ui.old_ui_tree.has_modal();
+        }
+        (ui.old_ui_tree.has_modal() || tree.has_modal()) && tree.beneath_modal_layer(parent)
+    }
+
+    /// The horizontal and vertical scroll since the last frame.
+    pub fn scroll_delta(&self) -> (f32, f32) {
+        if self.beneath_modal() {
+            return (0., 0.);
+        }
         self.ui.borrow().scroll_delta
     }
 
     pub fn pointer_in_element(&self, element: ElementHandle) -> bool {
         let ui = self.ui.borrow();
-        ui.old_ui_tree.elements[element.0]
-            .rectangle
-            .contains(ui.pointer_x, ui.pointer_y)
+        let tree = &ui.old_ui_tree;
+        if tree.has_modal() && tree.beneath_modal_layer(element) {
+            return false;
+        }
+        if !tree.elements[element.0].contains(ui.pointer_x, ui.pointer_y) {
+            return false;
+        }
+        // Elements beneath a popup don't receive pointer input.
+        match tree.popup_at(ui.pointer_x, ui.pointer_y) {
+            Some(popup) => tree.tree.is_descendent(element, popup),
+            None => true,
+        }
     }
 
+    /// Returns true if the pointer is over a focusable element within the element,
+    /// such as a button inside a scroll view.
+    pub fn pointer_in_focusable(&self, element: ElementHandle) -> bool {
+        let ui = self.ui.borrow();
+        ui.old_ui_tree
+            .focusable_at(element, ui.pointer_x, ui.pointer_y)
+    }
+
+    /// Claim the current press so that other widgets stop responding to it until it's released.
+    /// For example a scroll view claims a press that starts scrolling its content,
+    /// and a slider claims a press that drags its handle.
+    pub fn capture_pointer(&self) {
+        self.ui.borrow_mut().pointer_captured = true;
+    }
+
+    /// Returns true if a widget claimed the current press with `capture_pointer`.
+    /// Widgets that act when the pointer is released should ignore a claimed press.
+    pub fn pointer_captured(&self) -> bool {
+        self.ui.borrow().pointer_captured
+    }
+
+    /// Returns true if the pointer is over a popup.
+    pub fn pointer_in_popup(&self) -> bool {
+        let ui = self.ui.borrow();
+        ui.old_ui_tree
+            .popup_at(ui.pointer_x, ui.pointer_y)
+            .is_some()
+    }
+
+    /// Ask open popups, such as menus, to close during the next frame.
+    /// For example a menu item requests this when it's chosen.
+    pub fn request_close_popups(&self) {
+        let mut ui = self.ui.borrow_mut();
+        ui.close_popups_requested = true;
+        ui.request_animation_frame();
+    }
+
+    /// Returns true if popups were asked to close during the last frame.
+    pub fn closing_popups(&self) -> bool {
+        self.ui.borrow().closing_popups
+    }
+
+    /// Returns true if any pointer button was pressed anywhere outside the element.
+    /// Useful to dismiss popups.
+    pub fn pointer_down_outside(&self, element: ElementHandle) -> bool {
+        let ui = self.ui.borrow();
+        !ui.pointer_down.is_empty()
+            && !ui.old_ui_tree.elements[element.0].contains(ui.pointer_x, ui.pointer_y)
+    }
+
+    /// Returns true if the primary pointer button was pressed since the last frame.
     pub fn pointer_down(&self) -> bool {
-        self.ui.borrow().pointer_down
+        self.pointer_button_down(PointerButton::Primary)
     }
 
+    /// Returns true if the primary pointer button was released since the last frame.
     pub fn pointer_up(&self) -> bool {
-        self.ui.borrow().pointer_up
+        self.pointer_button_up(PointerButton::Primary)
+    }
+
+    pub fn pointer_button_down(&self, button: PointerButton) -> bool {
+        !self.beneath_modal() && self.ui.borrow().pointer_down.contains(&button)
+    }
+
+    pub fn pointer_button_up(&self, button: PointerButton) -> bool {
+        !self.beneath_modal() && self.ui.borrow().pointer_up.contains(&button)
+    }
+
+    /// Returns true if the key was pressed (or repeated) since the last frame.
+    pub fn key_pressed(&self, key: Key) -> bool {
+        !self.beneath_modal() && self.ui.borrow().keys_pressed.contains(&key)
+    }
+
+    /// Returns true if the key was pressed since the last frame, ignoring key repeats.
+    pub fn key_pressed_without_repeat(&self, key: Key) -> bool {
+        !self.beneath_modal() && self.ui.borrow().keys_pressed_without_repeat.contains(&key)
+    }
+
+    /// Returns true if the key was released since the last frame.
+    pub fn key_released(&self, key: Key) -> bool {
+        !self.beneath_modal() && self.ui.borrow().keys_released.contains(&key)
+    }
+
+    /// Returns true if the key is currently held down.
+    pub fn key_held(&self, key: Key) -> bool {
+        !self.beneath_modal() && self.ui.borrow().keys_held.contains(&key)
+    }
+
+    /// All keys pressed since the last frame, in the order they were pressed.
+    pub fn keys_pressed(&self) -> Vec<Key> {
+        if self.beneath_modal() {
+            return Vec::new();
+        }
+        self.ui.borrow().keys_pressed.clone()
+    }
+
+    /// Characters typed since the last frame.
+    pub fn characters_typed(&self) -> String {
+        if self.beneath_modal() {
+            return String::new();
+        }
+        self.ui.borrow().characters.clone()
+    }
+
+    pub fn modifiers(&self) -> Modifiers {
+        self.ui.borrow().modifiers()
     }
 
     pub fn element_rectangle(&self, element: ElementHandle) -> Rectangle {
@@ -557,3 +1154,68 @@ impl<'a> UIBuilder<'a> {
 
 #[derive(Debug, Copy, Clone)]
 pub struct WidgetHandle(pub(crate) usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a column clipped at 50 pixels with two 40 pixel tall fills,
+    /// and returns true if the pointer is in the second fill built the frame before.
+    fn pointer_in_clipped_fill(
+        ui: &mut UI,
+        last_frame: Option<ElementHandle>,
+    ) -> (bool, ElementHandle) {
+        let root = ui.edit();
+        let hovered = last_frame.is_some_and(|fill| root.pointer_in_element(fill));
+        let column = root.height(50.).clip().column();
+        column.height(40.).fill((1., 1., 1., 1.));
+        let fill = column.height(40.).fill((1., 1., 1., 1.)).handle();
+        drop(root);
+        ui.render();
+        (hovered, fill)
+    }
+
+    #[test]
+    fn clipped_parts_of_elements_dont_receive_the_pointer() {
+        let mut ui = UI::new();
+        ui.resize(100., 100.);
+        let (_, fill) = pointer_in_clipped_fill(&mut ui, None);
+
+        // The second fill is from 40 to 80 but clipped at 50.
+        ui.pointer_move(10., 45.);
+        let (hovered, fill) = pointer_in_clipped_fill(&mut ui, Some(fill));
+        assert!(hovered);
+        ui.pointer_move(10., 70.);
+        let (hovered, _) = pointer_in_clipped_fill(&mut ui, Some(fill));
+        assert!(!hovered);
+    }
+
+    /// Builds a frame with a focusable element and returns if it's focused.
+    fn focused_frame(ui: &mut UI, request_focus: bool) -> bool {
+        let focused = {
+            let root = ui.edit();
+            root.fit().focusable(1);
+            if request_focus {
+                root.request_focus(1);
+            }
+            root.is_focused(1)
+        };
+        ui.render();
+        focused
+    }
+
+    #[test]
+    fn only_primary_presses_remove_focus() {
+        let mut ui = UI::new();
+        ui.resize(100., 100.);
+        assert!(focused_frame(&mut ui, true));
+
+        ui.pointer_down(50., 50., PointerButton::Secondary);
+        assert!(focused_frame(&mut ui, false));
+        ui.pointer_up(50., 50., PointerButton::Secondary);
+        assert!(focused_frame(&mut ui, false));
+
+        ui.pointer_down(50., 50., PointerButton::Primary);
+        assert!(!focused_frame(&mut ui, false));
+    }
+}
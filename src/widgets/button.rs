@@ -23,15 +23,20 @@ impl Button {
     }
 
     /// Returns true if pressed
-    fn build(&mut self, parent: &UIBuilder, text: &str) -> bool {
+    fn build(&mut self, parent: &UIBuilder, id: u64, content: impl FnOnce(&UIBuilder)) -> bool {
         // Input
         let mut pressed = false;
         let mut pointer_in_element = false;
         if let Some(element) = self.element {
             pointer_in_element = parent.pointer_in_element(element);
             if parent.pointer_down() && pointer_in_element {
+                parent.request_focus(id);
                 self.pointer_held_down = true;
             }
+            // A press taken by something else, like a scroll view scrolling, is cancelled.
+            if parent.pointer_captured() {
+                self.pointer_held_down = false;
+            }
             // Perform a button press when the mouse is up within the button.
             if self.pointer_held_down && parent.pointer_up() && pointer_in_element {
                 pressed = true;
@@ -42,6 +47,12 @@ impl Button {
             }
         }
 
+        // A focused button can be pressed with Enter or Space.
+        if super::pressed_with_keyboard(parent, id) {
+            pressed = true;
+        }
+        let focused = parent.is_focused(id);
+
         let depressed = self.pointer_held_down && pointer_in_element;
 
         // Rendering
@@ -52,12 +63,14 @@ impl Button {
         };
 
         let top = parent.fit();
-        top.fill(color)
+        top.focusable(id);
+        let inner = super::focus_ring(&top, focused)
+            .fill(color)
             .padding(2.)
             .fill((0., 0., 0., 1.))
             .padding(20.)
-            .center_vertical()
-            .text(text);
+            .center_vertical();
+        content(&inner);
         self.element = Some(top.handle());
         pressed
     }
@@ -68,7 +81,20 @@ impl Widget for Button {}
 pub fn button_with_id(parent: &UIBuilder, id: u64, text: &str) -> bool {
     // Create or get an existing button.
     let mut button = parent.get_widget(id).1.unwrap_or(Box::new(Button::new()));
-    let pressed = button.build(parent, text);
+    let pressed = button.build(parent, id, |inner| {
+        inner.text(text);
+    });
+    parent.add_widget(id, button);
+    pressed
+}
+
+/// Create a button with an icon, named like "home" or given as a character.
+/// The icon font must be loaded with `UI::icon_font_from_bytes`.
+pub fn icon_button_with_id(parent: &UIBuilder, id: u64, icon: &str) -> bool {
+    let mut button = parent.get_widget(id).1.unwrap_or(Box::new(Button::new()));
+    let pressed = button.build(parent, id, |inner| {
+        inner.icon(icon);
+    });
     parent.add_widget(id, button);
     pressed
 }
@@ -81,3 +107,12 @@ pub fn button(parent: &UIBuilder, text: &str) -> bool {
     let id = super::calculate_id(text);
     button_with_id(parent, id, text)
 }
+
+/// Create a button with an icon, named like "home" or given as a character.
+/// Returns true if the button is pressed.
+/// Uses the icon for ID calculation.
+#[track_caller]
+pub fn icon_button(parent: &UIBuilder, icon: &str) -> bool {
+    let id = super::calculate_id(icon);
+    icon_button_with_id(parent, id, icon)
+}
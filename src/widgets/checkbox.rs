@@ -0,0 +1,79 @@
+use super::interpolation::*;
+use super::Checkable;
+use crate::ui::{ElementHandle, UIBuilder, Widget};
+
+pub const CHECKBOX_SIZE: f32 = 30.;
+pub const CHECKBOX_BORDER_COLOR: (f32, f32, f32, f32) = (0.8, 0.8, 0.8, 1.0);
+pub const CHECKBOX_CHECKED_COLOR: (f32, f32, f32, f32) = (0.8, 0.8, 0.8, 1.0);
+/// Milliseconds for the check animation to complete.
+const ANIMATION_TIME: f32 = 100.;
+
+pub struct Checkbox {
+    checkable: Checkable,
+    element: Option<ElementHandle>,
+}
+
+impl Checkbox {
+    fn new(checked: bool) -> Self {
+        Self {
+            checkable: Checkable::new(checked),
+            element: None,
+        }
+    }
+
+    /// Returns true if toggled
+    fn build(&mut self, parent: &UIBuilder, id: u64, checked: &mut bool, label: &str) -> bool {
+        // Input
+        let toggled = self.checkable.clicked(parent, id, self.element)
+            || super::pressed_with_keyboard(parent, id);
+        if toggled {
+            *checked = !*checked;
+        }
+        let t = self.checkable.animate(parent, *checked, ANIMATION_TIME);
+        let focused = parent.is_focused(id);
+
+        // Rendering
+        let top = parent.fit();
+        top.focusable(id);
+        let row = top.spaced_row(10.);
+        super::focus_ring(&row.center_vertical().fit(), focused)
+            .fill(CHECKBOX_BORDER_COLOR)
+            .padding(2.)
+            .fill(interpolate_color(
+                (0., 0., 0., 1.),
+                CHECKBOX_CHECKED_COLOR,
+                t,
+            ))
+            .width(CHECKBOX_SIZE)
+            .height(CHECKBOX_SIZE)
+            .center()
+            .text_size(CHECKBOX_SIZE * 0.8)
+            .text_color((0., 0., 0., t))
+            .text("✓");
+        row.center_vertical().text(label);
+
+        self.element = Some(top.handle());
+        toggled
+    }
+}
+
+impl Widget for Checkbox {}
+
+pub fn checkbox_with_id(parent: &UIBuilder, id: u64, checked: &mut bool, label: &str) -> bool {
+    let mut checkbox = parent
+        .get_widget(id)
+        .1
+        .unwrap_or_else(|| Box::new(Checkbox::new(*checked)));
+    let toggled = checkbox.build(parent, id, checked, label);
+    parent.add_widget(id, checkbox);
+    toggled
+}
+
+/// Create a checkbox with a label that edits `checked`.
+/// Returns true if the checkbox was toggled.
+/// Uses label text for ID calculation.
+#[track_caller]
+pub fn checkbox(parent: &UIBuilder, checked: &mut bool, label: &str) -> bool {
+    let id = super::calculate_id(label);
+    checkbox_with_id(parent, id, checked, label)
+}
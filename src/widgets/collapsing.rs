@@ -0,0 +1,114 @@
+use super::interpolation::*;
+use crate::ui::{ElementHandle, UIBuilder, Widget};
+
+pub const COLLAPSING_HEADER_COLOR: (f32, f32, f32, f32) = (0.2, 0.2, 0.2, 1.0);
+pub const COLLAPSING_HEADER_HOVER_COLOR: (f32, f32, f32, f32) = (0.25, 0.25, 0.25, 1.0);
+/// Milliseconds for a section to open or close.
+const ANIMATION_TIME: f32 = 200.;
+const DISCLOSURE_TEXT_SIZE: f32 = 14.;
+
+pub struct Collapsing {
+    open: bool,
+    /// 0. to 1. How open the section appears.
+    open_animate: Interpolate,
+    /// The height of the body the last time it was built.
+    body_height: f32,
+    header: Option<ElementHandle>,
+    body: Option<ElementHandle>,
+}
+
+impl Collapsing {
+    fn new() -> Self {
+        Self {
+            open: false,
+            open_animate: Interpolate::new(InterpolationCurve::Ease),
+            body_height: 0.,
+            header: None,
+            body: None,
+        }
+    }
+
+    fn build(
+        &mut self,
+        parent: &UIBuilder,
+        id: u64,
+        title: &str,
+        body: impl FnOnce(&UIBuilder),
+    ) -> bool {
+        // Input
+        let hovered = self.header.is_some_and(|h| parent.pointer_in_element(h));
+        if parent.pointer_down() && hovered {
+            parent.request_focus(id);
+            self.open = !self.open;
+        }
+        if super::pressed_with_keyboard(parent, id) {
+            self.open = !self.open;
+        }
+        let focused = parent.is_focused(id);
+
+        let delta = parent.animation_delta() / ANIMATION_TIME;
+        if self.open {
+            self.open_animate.add(delta);
+        } else {
+            self.open_animate.subtract(delta);
+        }
+        let animating = self.open_animate.not_one_or_zero();
+        if animating {
+            parent.request_animation_frame();
+        }
+        if let Some(body) = self.body {
+            self.body_height = parent.element_rectangle(body).height;
+        }
+
+        // Rendering
+        let column = parent.column();
+        let header = column.fit();
+        header.focusable(id);
+        let color = if hovered {
+            COLLAPSING_HEADER_HOVER_COLOR
+        } else {
+            COLLAPSING_HEADER_COLOR
+        };
+        let row = super::focus_ring(&header.horizontal_expander(), focused)
+            .fill(color)
+            .padding(10.)
+            .spaced_row(10.);
+        let glyph = if self.open { "▼" } else { "▶" };
+        row.center_vertical()
+            .text_size(DISCLOSURE_TEXT_SIZE)
+            .text(glyph);
+        row.center_vertical().text(title);
+        self.header = Some(header.handle());
+
+        // While animating the body is clipped to a height between closed and its full height.
+        self.body = None;
+        if animating {
+            let body_root = column
+                .height(self.body_height * self.open_animate.get())
+                .clip()
+                .fit();
+            body(&body_root);
+            self.body = Some(body_root.handle());
+        } else if self.open {
+            let body_root = column.fit();
+            body(&body_root);
+            self.body = Some(body_root.handle());
+        }
+        self.open
+    }
+}
+
+impl Widget for Collapsing {}
+
+/// Create a section with a header that opens and closes the body when clicked.
+/// `body` is only called while the section is open or animating.
+/// Returns true if the section is open.
+pub fn collapsing(parent: &UIBuilder, id: u64, title: &str, body: impl FnOnce(&UIBuilder)) -> bool {
+    let mut collapsing = parent
+        .get_widget(id)
+        .1
+        .unwrap_or_else(|| Box::new(Collapsing::new()));
+    let open = collapsing.build(parent, id, title, body);
+    parent.add_widget(id, collapsing);
+    open
+}
@@ -0,0 +1,326 @@
+use crate::ui::{ElementHandle, UIBuilder, Widget};
+
+/// The width and height of the saturation and value square.
+pub const COLOR_PICKER_SIZE: f32 = 250.;
+pub const HUE_STRIP_WIDTH: f32 = 30.;
+pub const SWATCH_SIZE: f32 = 60.;
+const MARKER_SIZE: f32 = 14.;
+
+/// The colors the hue strip blends between from top to bottom.
+const HUES: [(f32, f32, f32, f32); 7] = [
+    (1., 0., 0., 1.),
+    (1., 1., 0., 1.),
+    (0., 1., 0., 1.),
+    (0., 1., 1., 1.),
+    (0., 0., 1., 1.),
+    (1., 0., 1., 1.),
+    (1., 0., 0., 1.),
+];
+
+#[derive(Copy, Clone, PartialEq)]
+enum Dragging {
+    None,
+    Square,
+    Hue,
+}
+
+pub struct ColorPicker {
+    /// Hue, saturation and value from 0. to 1.
+    /// These are kept so that the hue isn't lost when the color is gray or black.
+    hsv: (f32, f32, f32),
+    /// The color last produced, to detect when it's changed elsewhere.
+    color: (f32, f32, f32, f32),
+    hex: String,
+    dragging: Dragging,
+    square: Option<ElementHandle>,
+    hue_strip: Option<ElementHandle>,
+}
+
+impl ColorPicker {
+    fn new(color: (f32, f32, f32, f32)) -> Self {
+        Self {
+            hsv: rgb_to_hsv(color),
+            color,
+            hex: String::new(),
+            dragging: Dragging::None,
+            square: None,
+            hue_strip: None,
+        }
+    }
+
+    /// Returns the pointer position within the element from 0. to 1. on each axis.
+    fn pointer_fraction(parent: &UIBuilder, element: ElementHandle) -> (f32, f32) {
+        let rectangle = parent.element_rectangle(element);
+        let (x, y) = parent.pointer_position();
+        (
+            ((x - rectangle.x) / rectangle.width).clamp(0., 1.),
+            ((y - rectangle.y) / rectangle.height).clamp(0., 1.),
+        )
+    }
+
+    /// Returns true if the color changed
+    fn build(&mut self, parent: &UIBuilder, id: u64, color: &mut (f32, f32, f32, f32)) -> bool {
+        let old_color = *color;
+        if *color != self.color {
+            self.hsv = rgb_to_hsv(*color);
+        }
+
+        // Input
+        if parent.pointer_down() {
+            if self.square.is_some_and(|e| parent.pointer_in_element(e)) {
+                self.dragging = Dragging::Square;
+            } else if self.hue_strip.is_some_and(|e| parent.pointer_in_element(e)) {
+                self.dragging = Dragging::Hue;
+            }
+            if self.dragging != Dragging::None {
+                parent.capture_pointer();
+            }
+        }
+        if parent.pointer_up() {
+            self.dragging = Dragging::None;
+        }
+        match (self.dragging, self.square, self.hue_strip) {
+            (Dragging::Square, Some(square), _) => {
+                let (x, y) = Self::pointer_fraction(parent, square);
+                self.hsv.1 = x;
+                self.hsv.2 = 1. - y;
+            }
+            (Dragging::Hue, _, Some(hue_strip)) => {
+                self.hsv.0 = Self::pointer_fraction(parent, hue_strip).1;
+            }
+            _ => {}
+        }
+        if self.dragging != Dragging::None {
+            *color = hsv_to_rgb(self.hsv, color.3);
+        }
+
+        // Rendering
+        let column = parent.spaced_column(10.);
+        let row = column.spaced_row(10.);
+
+        // Saturation increases to the right and value increases to the top.
+        let hue = hsv_to_rgb((self.hsv.0, 1., 1.), 1.);
+        let square = row.fit();
+        let square_area = square.width(COLOR_PICKER_SIZE).height(COLOR_PICKER_SIZE);
+        square_area
+            .gradient([(1., 1., 1., 1.), hue, hue, (1., 1., 1., 1.)])
+            .gradient([
+                (0., 0., 0., 0.),
+                (0., 0., 0., 0.),
+                (0., 0., 0., 1.),
+                (0., 0., 0., 1.),
+            ]);
+        square_area
+            .position_horizontal_pixels(self.hsv.1 * COLOR_PICKER_SIZE - MARKER_SIZE / 2.)
+            .position_vertical_pixels((1. - self.hsv.2) * COLOR_PICKER_SIZE - MARKER_SIZE / 2.)
+            .width(MARKER_SIZE)
+            .height(MARKER_SIZE)
+            .rounded_fill((1., 1., 1., 1.), MARKER_SIZE / 2.)
+            .padding(2.)
+            .rounded_fill(hsv_to_rgb(self.hsv, 1.), MARKER_SIZE / 2. - 2.);
+        self.square = Some(square.handle());
+
+        let hue_strip = row.fit();
+        let hue_area = hue_strip.width(HUE_STRIP_WIDTH).height(COLOR_PICKER_SIZE);
+        let segments = hue_area.column();
+        let segment_height = COLOR_PICKER_SIZE / (HUES.len() - 1) as f32;
+        for pair in HUES.windows(2) {
+            segments
+                .height(segment_height)
+                .gradient([pair[0], pair[0], pair[1], pair[1]]);
+        }
+        hue_area
+            .position_vertical_pixels(self.hsv.0 * COLOR_PICKER_SIZE - 2.)
+            .height(4.)
+            .fill((1., 1., 1., 1.));
+        self.hue_strip = Some(hue_strip.handle());
+
+        let mut alpha = color.3;
+        if super::slider(
+            &column.width(COLOR_PICKER_SIZE + 10. + HUE_STRIP_WIDTH),
+            super::calculate_id((id, 0)),
+            &mut alpha,
+            0.0..=1.0,
+        ) {
+            color.3 = alpha;
+        }
+
+        let bottom_row = column.spaced_row(10.);
+        bottom_row
+            .center_vertical()
+            .width(SWATCH_SIZE)
+            .height(SWATCH_SIZE)
+            .fill(*color);
+
+        // Show the color as hex unless it's being edited.
+        let hex_id = super::calculate_id((id, 1));
+        if !parent.is_focused(hex_id) {
+            self.hex = to_hex(*color);
+        }
+        // The hex field takes the rest of the picker's width.
+        let hex_field = bottom_row.width(COLOR_PICKER_SIZE + HUE_STRIP_WIDTH - SWATCH_SIZE);
+        let response = super::text_field_with_id(&hex_field, hex_id, &mut self.hex, "#RRGGBBAA");
+        if response.changed {
+            if let Some(typed) = from_hex(&self.hex) {
+                *color = typed;
+                self.hsv = rgb_to_hsv(typed);
+            }
+        }
+
+        self.color = *color;
+        *color != old_color
+    }
+}
+
+impl Widget for ColorPicker {}
+
+fn rgb_to_hsv(color: (f32, f32, f32, f32)) -> (f32, f32, f32) {
+    let (r, g, b, _) = color;
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+    let hue = if delta == 0. {
+        0.
+    } else if max == r {
+        ((g - b) / delta).rem_euclid(6.)
+    } else if max == g {
+        (b - r) / delta + 2.
+    } else {
+        (r - g) / delta + 4.
+    };
+    let saturation = if max == 0. { 0. } else { delta / max };
+    (hue / 6., saturation, max)
+}
+
+fn hsv_to_rgb(hsv: (f32, f32, f32), alpha: f32) -> (f32, f32, f32, f32) {
+    let (h, s, v) = hsv;
+    let h = (h * 6.).rem_euclid(6.);
+    let c = v * s;
+    let x = c * (1. - (h % 2. - 1.).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (c, x, 0.),
+        1 => (x, c, 0.),
+        2 => (0., c, x),
+        3 => (0., x, c),
+        4 => (x, 0., c),
+        _ => (c, 0., x),
+    };
+    let m = v - c;
+    (r + m, g + m, b + m, alpha)
+}
+
+fn to_hex(color: (f32, f32, f32, f32)) -> String {
+    let byte = |c: f32| (c.clamp(0., 1.) * 255.).round() as u8;
+    let hex = format!(
+        "#{:02X}{:02X}{:02X}",
+        byte(color.0),
+        byte(color.1),
+        byte(color.2)
+    );
+    if color.3 < 1. {
+        format!("{}{:02X}", hex, byte(color.3))
+    } else {
+        hex
+    }
+}
+
+/// Reads colors like "#FF8800" or "#FF880080".
+fn from_hex(text: &str) -> Option<(f32, f32, f32, f32)> {
+    let text = text.trim().trim_start_matches('#');
+    // Checking the digits also rejects the signs `from_str_radix` would accept.
+    if !(text.len() == 6 || text.len() == 8) || !text.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |i: usize| -> Option<f32> {
+        Some(u8::from_str_radix(text.get(i * 2..i * 2 + 2)?, 16).ok()? as f32 / 255.)
+    };
+    let alpha = if text.len() == 8 { channel(3)? } else { 1. };
+    Some((channel(0)?, channel(1)?, channel(2)?, alpha))
+}
+
+/// Create a color picker with a saturation and value square, a hue strip,
+/// an alpha slider, and a text field for hex colors.
+/// Returns true if the color changed.
+pub fn color_picker(parent: &UIBuilder, id: u64, color: &mut (f32, f32, f32, f32)) -> bool {
+    let mut color_picker = parent
+        .get_widget(id)
+        .1
+        .unwrap_or_else(|| Box::new(ColorPicker::new(*color)));
+    let changed = color_picker.build(parent, id, color);
+    parent.add_widget(id, color_picker);
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: (f32, f32, f32, f32), b: (f32, f32, f32, f32)) {
+        let close = |x: f32, y: f32| (x - y).abs() < 0.001;
+        assert!(
+            close(a.0, b.0) && close(a.1, b.1) && close(a.2, b.2) && close(a.3, b.3),
+            "{:?} != {:?}",
+            a,
+            b
+        );
+    }
+
+    #[test]
+    fn from_hex_reads_six_digits_as_opaque() {
+        assert_close(from_hex("#FF8800").unwrap(), (1., 136. / 255., 0., 1.));
+        assert_close(from_hex("ff8800").unwrap(), (1., 136. / 255., 0., 1.));
+        assert_close(from_hex("  #000000 ").unwrap(), (0., 0., 0., 1.));
+    }
+
+    #[test]
+    fn from_hex_reads_eight_digits_with_alpha() {
+        assert_close(
+            from_hex("#FF880080").unwrap(),
+            (1., 136. / 255., 0., 128. / 255.),
+        );
+        assert_close(from_hex("00000000").unwrap(), (0., 0., 0., 0.));
+    }
+
+    #[test]
+    fn from_hex_rejects_other_lengths() {
+        assert_eq!(from_hex(""), None);
+        assert_eq!(from_hex("#"), None);
+        assert_eq!(from_hex("#FFF"), None);
+        assert_eq!(from_hex("#FF88001"), None);
+        assert_eq!(from_hex("#FF8800800"), None);
+    }
+
+    #[test]
+    fn from_hex_rejects_characters_that_arent_digits() {
+        assert_eq!(from_hex("#GG8800"), None);
+        assert_eq!(from_hex("#+F+F+F"), None);
+        assert_eq!(from_hex("#FF88é0"), None);
+    }
+
+    #[test]
+    fn to_hex_leaves_out_alpha_when_opaque() {
+        assert_eq!(to_hex((1., 136. / 255., 0., 1.)), "#FF8800");
+        assert_eq!(to_hex((1., 136. / 255., 0., 128. / 255.)), "#FF880080");
+        // Channels outside 0. to 1. are clamped.
+        assert_eq!(to_hex((2., -1., 0., 1.)), "#FF0000");
+    }
+
+    #[test]
+    fn hex_round_trips() {
+        for text in &["#FF8800", "#12345678", "#000000", "#FFFFFF00"] {
+            assert_eq!(&to_hex(from_hex(text).unwrap()), text);
+        }
+    }
+
+    #[test]
+    fn hsv_round_trips() {
+        for &color in &[
+            (1., 0., 0., 1.),
+            (0.2, 0.6, 0.4, 1.),
+            (0.5, 0.1, 0.9, 0.5),
+            (0.3, 0.3, 0.3, 1.),
+        ] {
+            assert_close(hsv_to_rgb(rgb_to_hsv(color), color.3), color);
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::input::Key;
+use crate::ui::UIBuilder;
+
+pub const BACKDROP_COLOR: (f32, f32, f32, f32) = (0.0, 0.0, 0.0, 0.6);
+pub const DIALOG_COLOR: (f32, f32, f32, f32) = (0.15, 0.15, 0.15, 1.0);
+pub const DIALOG_BORDER_COLOR: (f32, f32, f32, f32) = (0.8, 0.8, 0.8, 1.0);
+/// The width messages in alerts and confirmations wrap to.
+pub const DIALOG_MESSAGE_WIDTH: f32 = 500.;
+
+/// Show a modal dialog centered over a dimmed backdrop.
+/// Nothing beneath the dialog receives input while it's shown.
+/// `content` adds the body of the dialog, and a button is added for each action.
+/// Pressing Escape chooses the last action, which is usually to cancel.
+/// Returns the index of the action chosen this frame.
+pub fn dialog(
+    parent: &UIBuilder,
+    id: u64,
+    title: &str,
+    actions: &[&str],
+    content: impl FnOnce(&UIBuilder),
+) -> Option<usize> {
+    let column = parent
+        .modal()
+        .fill(BACKDROP_COLOR)
+        .center()
+        .fit()
+        .fill(DIALOG_BORDER_COLOR)
+        .padding(2.)
+        .fill(DIALOG_COLOR)
+        .padding(30.)
+        .spaced_column(20.);
+    column.text(title);
+    content(&column);
+
+    let mut chosen = None;
+    let row = column.spaced_row(10.);
+    for (i, action) in actions.iter().enumerate() {
+        let button_id = super::calculate_id((id, i));
+        if super::button_with_id(&row, button_id, action) {
+            chosen = Some(i);
+        }
+    }
+
+    if column.key_pressed(Key::Escape) && !actions.is_empty() {
+        chosen = Some(actions.len() - 1);
+    }
+    chosen
+}
+
+/// Show a modal message with an OK button.
+/// Returns true when the message is dismissed.
+pub fn alert(parent: &UIBuilder, id: u64, title: &str, message: &str) -> bool {
+    dialog(parent, id, title, &["OK"], |content| {
+        content.width(DIALOG_MESSAGE_WIDTH).paragraph(message);
+    })
+    .is_some()
+}
+
+/// Show a modal message with OK and Cancel buttons.
+/// Returns `Some(true)` if OK was chosen and `Some(false)` if it was cancelled.
+pub fn confirm(parent: &UIBuilder, id: u64, title: &str, message: &str) -> Option<bool> {
+    dialog(parent, id, title, &["OK", "Cancel"], |content| {
+        content.width(DIALOG_MESSAGE_WIDTH).paragraph(message);
+    })
+    .map(|action| action == 0)
+}
@@ -29,6 +29,8 @@ impl Drag {
                     pointer_position.0 - element_rectangle.x,
                     pointer_position.1 - element_rectangle.y,
                 );
+                // Stop a scroll view from scrolling while the element is dragged.
+                parent.capture_pointer();
                 self.dragging = true;
             }
 
@@ -41,10 +43,20 @@ impl Drag {
                 let pointer_position = parent.pointer_position();
                 self.position = (
                     pointer_position.0 - root_rectangle.x - self.offset.0,
-                    pointer_position.1 - root_rectangle.x - self.offset.1,
+                    pointer_position.1 - root_rectangle.y - self.offset.1,
                 );
             }
         }
         self.position
     }
+
+    /// Returns true while the element is held by the pointer.
+    pub fn dragging(&self) -> bool {
+        self.dragging
+    }
+
+    /// Move the dragged element, for example in response to keyboard input.
+    pub fn set_position(&mut self, position: (f32, f32)) {
+        self.position = position;
+    }
 }
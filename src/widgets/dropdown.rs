@@ -0,0 +1,179 @@
+use crate::input::Key;
+use crate::ui::{ElementHandle, UIBuilder, Widget};
+
+pub const DROPDOWN_COLOR: (f32, f32, f32, f32) = (0.8, 0.8, 0.8, 1.0);
+pub const DROPDOWN_HIGHLIGHT_COLOR: (f32, f32, f32, f32) = (0.3, 0.3, 0.3, 1.0);
+/// The width of the selected option, not including the arrow.
+pub const DROPDOWN_WIDTH: f32 = 300.;
+
+pub struct Dropdown {
+    open: bool,
+    element: Option<ElementHandle>,
+    popup: Option<ElementHandle>,
+    options: Vec<ElementHandle>,
+    /// The option under the pointer or chosen with the arrow keys while open.
+    highlighted: usize,
+}
+
+impl Dropdown {
+    fn new() -> Self {
+        Self {
+            open: false,
+            element: None,
+            popup: None,
+            options: Vec::new(),
+            highlighted: 0,
+        }
+    }
+
+    fn set_open(&mut self, open: bool, selected: usize) {
+        self.open = open;
+        self.highlighted = selected;
+    }
+
+    /// Returns true if the selection changed
+    fn build(
+        &mut self,
+        parent: &UIBuilder,
+        id: u64,
+        options: &[&str],
+        selected: &mut usize,
+    ) -> bool {
+        let old_selected = *selected;
+        let last_option = options.len().saturating_sub(1);
+
+        // Input
+        if let Some(element) = self.element {
+            if parent.pointer_down() && parent.pointer_in_element(element) {
+                parent.request_focus(id);
+                self.set_open(!self.open, *selected);
+            }
+        }
+
+        if let (true, Some(popup)) = (self.open, self.popup) {
+            // Keep focus while choosing an option.
+            if parent.pointer_down() && parent.pointer_in_element(popup) {
+                parent.request_focus(id);
+            }
+            for (i, option) in self.options.iter().enumerate() {
+                if parent.pointer_in_element(*option) {
+                    self.highlighted = i;
+                    if parent.pointer_up() {
+                        *selected = i;
+                        self.open = false;
+                    }
+                }
+            }
+
+            // Clicking anywhere else or another popup closing closes the popup.
+            let in_element = self.element.is_some_and(|e| parent.pointer_in_element(e));
+            if (parent.pointer_down_outside(popup) && !in_element) || parent.closing_popups() {
+                self.open = false;
+            }
+        }
+
+        let focused = parent.is_focused(id);
+        if focused {
+            for key in parent.keys_pressed() {
+                match (self.open, key) {
+                    (false, Key::Up) => *selected = selected.saturating_sub(1),
+                    (false, Key::Down) => *selected += 1,
+                    (true, Key::Up) => self.highlighted = self.highlighted.saturating_sub(1),
+                    (true, Key::Down) => self.highlighted = (self.highlighted + 1).min(last_option),
+                    (true, Key::Home) => self.highlighted = 0,
+                    (true, Key::End) => self.highlighted = last_option,
+                    (true, Key::Escape) => self.open = false,
+                    _ => {}
+                }
+            }
+            // Enter and Space open the list and choose the highlighted option.
+            // Holding them down doesn't keep opening and choosing.
+            if super::pressed_with_keyboard(parent, id) {
+                if self.open {
+                    *selected = self.highlighted;
+                    self.open = false;
+                } else {
+                    self.set_open(true, *selected);
+                }
+            }
+        } else {
+            self.open = false;
+        }
+        *selected = (*selected).min(last_option);
+
+        // Rendering
+        // The popup is positioned beneath where the dropdown was last frame.
+        let previous_rectangle = self.element.map(|e| parent.element_rectangle(e));
+        let top = parent.fit();
+        top.focusable(id);
+        let row = super::focus_ring(&top, focused)
+            .fill(DROPDOWN_COLOR)
+            .padding(2.)
+            .fill((0., 0., 0., 1.))
+            .padding(20.)
+            .spaced_row(20.);
+        row.width(DROPDOWN_WIDTH)
+            .center_vertical()
+            .ellipsis()
+            .text(options.get(*selected).copied().unwrap_or(""));
+        row.center_vertical()
+            .text(if self.open { "▲" } else { "▼" });
+        self.element = Some(top.handle());
+
+        self.options.clear();
+        self.popup = None;
+        if let (true, Some(rectangle)) = (self.open, previous_rectangle) {
+            let popup = parent.popup(rectangle.x, rectangle.y + rectangle.height);
+            let column = popup
+                .fill(DROPDOWN_COLOR)
+                .padding(2.)
+                .fill((0., 0., 0., 1.))
+                .column();
+            for (i, option) in options.iter().enumerate() {
+                let color = if i == self.highlighted {
+                    DROPDOWN_HIGHLIGHT_COLOR
+                } else {
+                    (0., 0., 0., 0.)
+                };
+                let option_root = column.fit();
+                option_root
+                    .width(rectangle.width - 4.)
+                    .fill(color)
+                    .padding(20.)
+                    .ellipsis()
+                    .text(option);
+                self.options.push(option_root.handle());
+            }
+            self.popup = Some(popup.handle());
+        }
+
+        *selected != old_selected
+    }
+}
+
+impl Widget for Dropdown {}
+
+pub fn dropdown_with_id(
+    parent: &UIBuilder,
+    id: u64,
+    options: &[&str],
+    selected: &mut usize,
+) -> bool {
+    let mut dropdown = parent
+        .get_widget(id)
+        .1
+        .unwrap_or_else(|| Box::new(Dropdown::new()));
+    let changed = dropdown.build(parent, id, options, selected);
+    parent.add_widget(id, dropdown);
+    changed
+}
+
+/// Create a dropdown that shows the selected option and opens a list of options when clicked.
+/// `selected` is the index of the selected option.
+/// Returns true if the selection changed.
+/// Uses the options for ID calculation.
+#[track_caller]
+pub fn dropdown(parent: &UIBuilder, options: &[&str], selected: &mut usize) -> bool {
+    let id = super::calculate_id(options);
+    dropdown_with_id(parent, id, options, selected)
+}
@@ -1,6 +1,10 @@
 use super::drag::Drag;
+use crate::input::Key;
 use crate::ui::{UIBuilder, Widget};
 
+/// How far the divider moves per arrow key press when focused.
+const KEYBOARD_STEP: f32 = 10.;
+
 pub const DEFAULT_COLOR: (f32, f32, f32, f32) = (0.8, 0.8, 0.8, 1.0);
 
 pub struct HorizontalDivider {
@@ -17,14 +21,41 @@ impl HorizontalDivider {
     }
 
     /// Returns true if pressed
-    fn build<'a>(&mut self, parent: &UIBuilder<'a>) -> (UIBuilder<'a>, UIBuilder<'a>) {
+    fn build<'a>(&mut self, parent: &UIBuilder<'a>, id: u64) -> (UIBuilder<'a>, UIBuilder<'a>) {
         // Move the handle
-        self.first_section_height = self.drag.update(parent).1;
+        let mut position = self.drag.update(parent);
+
+        // Clicking the handle focuses the divider.
+        if let Some((_, handle)) = self.drag.root_and_element {
+            if parent.pointer_down() && parent.pointer_in_element(handle) {
+                parent.request_focus(id);
+            }
+        }
+
+        // The arrow keys move a focused divider.
+        let focused = parent.is_focused(id);
+        if focused {
+            if parent.key_pressed(Key::Up) {
+                position.1 -= KEYBOARD_STEP;
+            }
+            if parent.key_pressed(Key::Down) {
+                position.1 += KEYBOARD_STEP;
+            }
+            position.1 = position.1.max(0.);
+            self.drag.set_position(position);
+        }
+        self.first_section_height = position.1;
 
         // Rendering
         let column = parent.column();
         let first_section = column.height(self.first_section_height);
-        let top = column.height(10.).horizontal_expander().fill(DEFAULT_COLOR);
+        let color = if focused {
+            super::FOCUS_RING_COLOR
+        } else {
+            DEFAULT_COLOR
+        };
+        let top = column.height(10.).horizontal_expander().fill(color);
+        top.focusable(id);
         let second_section = column.expander();
 
         self.drag.root_and_element = Some((column.handle(), top.handle()));
@@ -41,7 +72,7 @@ pub fn horizontal_divider<'a>(parent: &UIBuilder<'a>, id: u64) -> (UIBuilder<'a>
         .get_widget(id)
         .1
         .unwrap_or(Box::new(HorizontalDivider::new()));
-    let sections = item.build(parent);
+    let sections = item.build(parent, id);
     parent.add_widget(id, item);
     sections
 }
@@ -0,0 +1,42 @@
+use super::material_icons::MATERIAL_ICONS;
+
+/// Returns the character for a Material icon name, like "home" or "arrow_back".
+pub fn material_icon(name: &str) -> Option<char> {
+    MATERIAL_ICONS
+        .binary_search_by(|(icon_name, _)| (*icon_name).cmp(name))
+        .ok()
+        .map(|i| MATERIAL_ICONS[i].1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn icons_are_sorted_and_unique() {
+        // The lookup is a binary search, so it relies on this order.
+        for pair in MATERIAL_ICONS.windows(2) {
+            assert!(
+                pair[0].0 < pair[1].0,
+                "{} is before {}",
+                pair[0].0,
+                pair[1].0
+            );
+        }
+    }
+
+    #[test]
+    fn material_icon_finds_names() {
+        assert_eq!(material_icon("home"), Some('\u{e88a}'));
+        assert_eq!(material_icon("3d_rotation"), Some('\u{e84d}'));
+        let (last_name, last_codepoint) = MATERIAL_ICONS[MATERIAL_ICONS.len() - 1];
+        assert_eq!(material_icon(last_name), Some(last_codepoint));
+    }
+
+    #[test]
+    fn material_icon_returns_none_for_unknown_names() {
+        assert_eq!(material_icon("not_an_icon"), None);
+        assert_eq!(material_icon(""), None);
+        assert_eq!(material_icon("Home"), None);
+    }
+}
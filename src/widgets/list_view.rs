@@ -0,0 +1,78 @@
+use crate::ui::UIBuilder;
+use std::ops::Range;
+
+/// Create a scrolling list of rows that are all the same height.
+/// `build_row` is only called for rows within the view, so long lists stay fast.
+/// Space is reserved for the other rows so the scrollbar reflects the whole list.
+pub fn list_view(
+    parent: &UIBuilder,
+    id: u64,
+    item_count: usize,
+    row_height: f32,
+    mut build_row: impl FnMut(&UIBuilder, usize),
+) {
+    let content = super::scroll_view(parent, id);
+    let (offset, (_, mut view_height)) = super::scroll_view::scroll_view_viewport(parent, id);
+    // Until the view has been drawn assume it could be as tall as the window.
+    if view_height == 0. {
+        view_height = parent.window_size().1;
+    }
+
+    let row_height = row_height.max(1.);
+    let rows = visible_rows(offset.1, view_height, row_height, item_count);
+
+    let column = content.column();
+    column.height(rows.start as f32 * row_height);
+    for index in rows.clone() {
+        build_row(&column.height(row_height), index);
+    }
+    column.height((item_count - rows.end) as f32 * row_height);
+}
+
+/// Returns the rows that can be seen when the list is scrolled down by `offset`.
+/// A row is added at the end for one that's partially scrolled into view.
+fn visible_rows(offset: f32, view_height: f32, row_height: f32, item_count: usize) -> Range<usize> {
+    let first = ((offset / row_height).floor().max(0.) as usize).min(item_count);
+    let visible = (view_height / row_height).ceil() as usize + 1;
+    let last = (first + visible).min(item_count);
+    first..last
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn visible_rows_at_the_top() {
+        assert_eq!(visible_rows(0., 100., 20., 1000), 0..6);
+    }
+
+    #[test]
+    fn visible_rows_partway_through_a_row() {
+        assert_eq!(visible_rows(50., 100., 20., 1000), 2..8);
+    }
+
+    #[test]
+    fn visible_rows_stop_at_the_end() {
+        assert_eq!(visible_rows(1000., 100., 20., 55), 50..55);
+        assert_eq!(visible_rows(5000., 100., 20., 55), 55..55);
+        assert_eq!(visible_rows(0., 100., 20., 0), 0..0);
+    }
+
+    #[test]
+    fn visible_rows_ignore_overscroll_above_the_top() {
+        assert_eq!(visible_rows(-30., 100., 20., 1000), 0..6);
+    }
+
+    #[test]
+    fn spacers_and_rows_add_up_to_the_whole_list() {
+        let (row_height, item_count) = (20., 100);
+        for offset in [0., 15., 333., 1900., 2500.].iter() {
+            let rows = visible_rows(*offset, 100., row_height, item_count);
+            let before = rows.start as f32 * row_height;
+            let after = (item_count - rows.end) as f32 * row_height;
+            let built = rows.len() as f32 * row_height;
+            assert_eq!(before + built + after, item_count as f32 * row_height);
+        }
+    }
+}
@@ -0,0 +1,983 @@
+//! Codepoints of the icons in the bundled Material Icons font, sorted by name.
+//! Generated from the font's ligatures.
+
+pub(crate) const MATERIAL_ICONS: &[(&str, char)] = &[
+    ("3d_rotation", '\u{e84d}'),
+    ("ac_unit", '\u{eb3b}'),
+    ("access_alarm", '\u{e190}'),
+    ("access_alarms", '\u{e191}'),
+    ("access_time", '\u{e192}'),
+    ("accessibility", '\u{e84e}'),
+    ("accessible", '\u{e914}'),
+    ("account_balance", '\u{e84f}'),
+    ("account_balance_wallet", '\u{e850}'),
+    ("account_box", '\u{e851}'),
+    ("account_circle", '\u{e853}'),
+    ("adb", '\u{e60e}'),
+    ("add", '\u{e145}'),
+    ("add_a_photo", '\u{e439}'),
+    ("add_alarm", '\u{e193}'),
+    ("add_alert", '\u{e003}'),
+    ("add_box", '\u{e146}'),
+    ("add_circle", '\u{e147}'),
+    ("add_circle_outline", '\u{e148}'),
+    ("add_location", '\u{e567}'),
+    ("add_shopping_cart", '\u{e854}'),
+    ("add_to_photos", '\u{e39d}'),
+    ("add_to_queue", '\u{e05c}'),
+    ("adjust", '\u{e39e}'),
+    ("airline_seat_flat", '\u{e630}'),
+    ("airline_seat_flat_angled", '\u{e631}'),
+    ("airline_seat_individual_suite", '\u{e632}'),
+    ("airline_seat_legroom_extra", '\u{e633}'),
+    ("airline_seat_legroom_normal", '\u{e634}'),
+    ("airline_seat_legroom_reduced", '\u{e635}'),
+    ("airline_seat_recline_extra", '\u{e636}'),
+    ("airline_seat_recline_normal", '\u{e637}'),
+    ("airplanemode_active", '\u{e195}'),
+    ("airplanemode_inactive", '\u{e194}'),
+    ("airplanemode_off", '\u{e194}'),
+    ("airplanemode_on", '\u{e195}'),
+    ("airplay", '\u{e055}'),
+    ("airport_shuttle", '\u{eb3c}'),
+    ("alarm", '\u{e855}'),
+    ("alarm_add", '\u{e856}'),
+    ("alarm_off", '\u{e857}'),
+    ("alarm_on", '\u{e858}'),
+    ("album", '\u{e019}'),
+    ("all_inclusive", '\u{eb3d}'),
+    ("all_out", '\u{e90b}'),
+    ("android", '\u{e859}'),
+    ("announcement", '\u{e85a}'),
+    ("apps", '\u{e5c3}'),
+    ("archive", '\u{e149}'),
+    ("arrow_back", '\u{e5c4}'),
+    ("arrow_downward", '\u{e5db}'),
+    ("arrow_drop_down", '\u{e5c5}'),
+    ("arrow_drop_down_circle", '\u{e5c6}'),
+    ("arrow_drop_up", '\u{e5c7}'),
+    ("arrow_forward", '\u{e5c8}'),
+    ("arrow_upward", '\u{e5d8}'),
+    ("art_track", '\u{e060}'),
+    ("aspect_ratio", '\u{e85b}'),
+    ("assessment", '\u{e85c}'),
+    ("assignment", '\u{e85d}'),
+    ("assignment_ind", '\u{e85e}'),
+    ("assignment_late", '\u{e85f}'),
+    ("assignment_return", '\u{e860}'),
+    ("assignment_returned", '\u{e861}'),
+    ("assignment_turned_in", '\u{e862}'),
+    ("assistant", '\u{e39f}'),
+    ("assistant_photo", '\u{e3a0}'),
+    ("attach_file", '\u{e226}'),
+    ("attach_money", '\u{e227}'),
+    ("attachment", '\u{e2bc}'),
+    ("audiotrack", '\u{e3a1}'),
+    ("autorenew", '\u{e863}'),
+    ("av_timer", '\u{e01b}'),
+    ("backspace", '\u{e14a}'),
+    ("backup", '\u{e864}'),
+    ("battery_alert", '\u{e19c}'),
+    ("battery_charging_full", '\u{e1a3}'),
+    ("battery_full", '\u{e1a4}'),
+    ("battery_std", '\u{e1a5}'),
+    ("battery_unknown", '\u{e1a6}'),
+    ("beach_access", '\u{eb3e}'),
+    ("beenhere", '\u{e52d}'),
+    ("block", '\u{e14b}'),
+    ("bluetooth", '\u{e1a7}'),
+    ("bluetooth_audio", '\u{e60f}'),
+    ("bluetooth_connected", '\u{e1a8}'),
+    ("bluetooth_disabled", '\u{e1a9}'),
+    ("bluetooth_searching", '\u{e1aa}'),
+    ("blur_circular", '\u{e3a2}'),
+    ("blur_linear", '\u{e3a3}'),
+    ("blur_off", '\u{e3a4}'),
+    ("blur_on", '\u{e3a5}'),
+    ("book", '\u{e865}'),
+    ("bookmark", '\u{e866}'),
+    ("bookmark_border", '\u{e867}'),
+    ("bookmark_outline", '\u{e867}'),
+    ("border_all", '\u{e228}'),
+    ("border_bottom", '\u{e229}'),
+    ("border_clear", '\u{e22a}'),
+    ("border_color", '\u{e22b}'),
+    ("border_horizontal", '\u{e22c}'),
+    ("border_inner", '\u{e22d}'),
+    ("border_left", '\u{e22e}'),
+    ("border_outer", '\u{e22f}'),
+    ("border_right", '\u{e230}'),
+    ("border_style", '\u{e231}'),
+    ("border_top", '\u{e232}'),
+    ("border_vertical", '\u{e233}'),
+    ("branding_watermark", '\u{e06b}'),
+    ("brightness_1", '\u{e3a6}'),
+    ("brightness_2", '\u{e3a7}'),
+    ("brightness_3", '\u{e3a8}'),
+    ("brightness_4", '\u{e3a9}'),
+    ("brightness_5", '\u{e3aa}'),
+    ("brightness_6", '\u{e3ab}'),
+    ("brightness_7", '\u{e3ac}'),
+    ("brightness_auto", '\u{e1ab}'),
+    ("brightness_high", '\u{e1ac}'),
+    ("brightness_low", '\u{e1ad}'),
+    ("brightness_medium", '\u{e1ae}'),
+    ("broken_image", '\u{e3ad}'),
+    ("brush", '\u{e3ae}'),
+    ("bubble_chart", '\u{e6dd}'),
+    ("bug_report", '\u{e868}'),
+    ("build", '\u{e869}'),
+    ("burst_mode", '\u{e43c}'),
+    ("business", '\u{e0af}'),
+    ("business_center", '\u{eb3f}'),
+    ("cached", '\u{e86a}'),
+    ("cake", '\u{e7e9}'),
+    ("call", '\u{e0b0}'),
+    ("call_end", '\u{e0b1}'),
+    ("call_made", '\u{e0b2}'),
+    ("call_merge", '\u{e0b3}'),
+    ("call_missed", '\u{e0b4}'),
+    ("call_missed_outgoing", '\u{e0e4}'),
+    ("call_received", '\u{e0b5}'),
+    ("call_split", '\u{e0b6}'),
+    ("call_to_action", '\u{e06c}'),
+    ("camera", '\u{e3af}'),
+    ("camera_alt", '\u{e3b0}'),
+    ("camera_enhance", '\u{e8fc}'),
+    ("camera_front", '\u{e3b1}'),
+    ("camera_rear", '\u{e3b2}'),
+    ("camera_roll", '\u{e3b3}'),
+    ("cancel", '\u{e5c9}'),
+    ("card_giftcard", '\u{e8f6}'),
+    ("card_membership", '\u{e8f7}'),
+    ("card_travel", '\u{e8f8}'),
+    ("casino", '\u{eb40}'),
+    ("cast", '\u{e307}'),
+    ("cast_connected", '\u{e308}'),
+    ("center_focus_strong", '\u{e3b4}'),
+    ("center_focus_weak", '\u{e3b5}'),
+    ("change_history", '\u{e86b}'),
+    ("chat", '\u{e0b7}'),
+    ("chat_bubble", '\u{e0ca}'),
+    ("chat_bubble_outline", '\u{e0cb}'),
+    ("check", '\u{e5ca}'),
+    ("check_box", '\u{e834}'),
+    ("check_box_outline_blank", '\u{e835}'),
+    ("check_circle", '\u{e86c}'),
+    ("chevron_left", '\u{e5cb}'),
+    ("chevron_right", '\u{e5cc}'),
+    ("child_care", '\u{eb41}'),
+    ("child_friendly", '\u{eb42}'),
+    ("chrome_reader_mode", '\u{e86d}'),
+    ("class", '\u{e86e}'),
+    ("clear", '\u{e14c}'),
+    ("clear_all", '\u{e0b8}'),
+    ("close", '\u{e5cd}'),
+    ("closed_caption", '\u{e01c}'),
+    ("cloud", '\u{e2bd}'),
+    ("cloud_circle", '\u{e2be}'),
+    ("cloud_done", '\u{e2bf}'),
+    ("cloud_download", '\u{e2c0}'),
+    ("cloud_off", '\u{e2c1}'),
+    ("cloud_queue", '\u{e2c2}'),
+    ("cloud_upload", '\u{e2c3}'),
+    ("code", '\u{e86f}'),
+    ("collections", '\u{e3b6}'),
+    ("collections_bookmark", '\u{e431}'),
+    ("color_lens", '\u{e3b7}'),
+    ("colorize", '\u{e3b8}'),
+    ("comment", '\u{e0b9}'),
+    ("compare", '\u{e3b9}'),
+    ("compare_arrows", '\u{e915}'),
+    ("computer", '\u{e30a}'),
+    ("confirmation_num", '\u{e638}'),
+    ("confirmation_number", '\u{e638}'),
+    ("contact_mail", '\u{e0d0}'),
+    ("contact_phone", '\u{e0cf}'),
+    ("contacts", '\u{e0ba}'),
+    ("content_copy", '\u{e14d}'),
+    ("content_cut", '\u{e14e}'),
+    ("content_paste", '\u{e14f}'),
+    ("control_point", '\u{e3ba}'),
+    ("control_point_duplicate", '\u{e3bb}'),
+    ("copyright", '\u{e90c}'),
+    ("create", '\u{e150}'),
+    ("create_new_folder", '\u{e2cc}'),
+    ("credit_card", '\u{e870}'),
+    ("crop", '\u{e3be}'),
+    ("crop_16_9", '\u{e3bc}'),
+    ("crop_3_2", '\u{e3bd}'),
+    ("crop_5_4", '\u{e3bf}'),
+    ("crop_7_5", '\u{e3c0}'),
+    ("crop_din", '\u{e3c1}'),
+    ("crop_free", '\u{e3c2}'),
+    ("crop_landscape", '\u{e3c3}'),
+    ("crop_original", '\u{e3c4}'),
+    ("crop_portrait", '\u{e3c5}'),
+    ("crop_rotate", '\u{e437}'),
+    ("crop_square", '\u{e3c6}'),
+    ("dashboard", '\u{e871}'),
+    ("data_usage", '\u{e1af}'),
+    ("date_range", '\u{e916}'),
+    ("dehaze", '\u{e3c7}'),
+    ("delete", '\u{e872}'),
+    ("delete_forever", '\u{e92b}'),
+    ("delete_sweep", '\u{e16c}'),
+    ("description", '\u{e873}'),
+    ("desktop_mac", '\u{e30b}'),
+    ("desktop_windows", '\u{e30c}'),
+    ("details", '\u{e3c8}'),
+    ("developer_board", '\u{e30d}'),
+    ("developer_mode", '\u{e1b0}'),
+    ("device_hub", '\u{e335}'),
+    ("devices", '\u{e1b1}'),
+    ("devices_other", '\u{e337}'),
+    ("dialer_sip", '\u{e0bb}'),
+    ("dialpad", '\u{e0bc}'),
+    ("directions", '\u{e52e}'),
+    ("directions_bike", '\u{e52f}'),
+    ("directions_boat", '\u{e532}'),
+    ("directions_bus", '\u{e530}'),
+    ("directions_car", '\u{e531}'),
+    ("directions_ferry", '\u{e532}'),
+    ("directions_railway", '\u{e534}'),
+    ("directions_run", '\u{e566}'),
+    ("directions_subway", '\u{e533}'),
+    ("directions_train", '\u{e534}'),
+    ("directions_transit", '\u{e535}'),
+    ("directions_walk", '\u{e536}'),
+    ("disc_full", '\u{e610}'),
+    ("dnd_forwardslash", '\u{e611}'),
+    ("dns", '\u{e875}'),
+    ("do_not_disturb", '\u{e612}'),
+    ("do_not_disturb_alt", '\u{e611}'),
+    ("do_not_disturb_off", '\u{e643}'),
+    ("do_not_disturb_on", '\u{e644}'),
+    ("dock", '\u{e30e}'),
+    ("domain", '\u{e7ee}'),
+    ("done", '\u{e876}'),
+    ("done_all", '\u{e877}'),
+    ("donut_large", '\u{e917}'),
+    ("donut_small", '\u{e918}'),
+    ("drafts", '\u{e151}'),
+    ("drag_handle", '\u{e25d}'),
+    ("drive_eta", '\u{e613}'),
+    ("dvr", '\u{e1b2}'),
+    ("edit", '\u{e3c9}'),
+    ("edit_location", '\u{e568}'),
+    ("eject", '\u{e8fb}'),
+    ("email", '\u{e0be}'),
+    ("enhance_photo_translate", '\u{e8fc}'),
+    ("enhanced_encryption", '\u{e63f}'),
+    ("equalizer", '\u{e01d}'),
+    ("error", '\u{e000}'),
+    ("error_outline", '\u{e001}'),
+    ("euro_symbol", '\u{e926}'),
+    ("ev_station", '\u{e56d}'),
+    ("event", '\u{e878}'),
+    ("event_available", '\u{e614}'),
+    ("event_busy", '\u{e615}'),
+    ("event_note", '\u{e616}'),
+    ("event_seat", '\u{e903}'),
+    ("exit_to_app", '\u{e879}'),
+    ("expand_less", '\u{e5ce}'),
+    ("expand_more", '\u{e5cf}'),
+    ("explicit", '\u{e01e}'),
+    ("explore", '\u{e87a}'),
+    ("exposure", '\u{e3ca}'),
+    ("exposure_minus_1", '\u{e3cb}'),
+    ("exposure_minus_2", '\u{e3cc}'),
+    ("exposure_neg_1", '\u{e3cb}'),
+    ("exposure_neg_2", '\u{e3cc}'),
+    ("exposure_plus_1", '\u{e3cd}'),
+    ("exposure_plus_2", '\u{e3ce}'),
+    ("exposure_zero", '\u{e3cf}'),
+    ("extension", '\u{e87b}'),
+    ("face", '\u{e87c}'),
+    ("fast_forward", '\u{e01f}'),
+    ("fast_rewind", '\u{e020}'),
+    ("favorite", '\u{e87d}'),
+    ("favorite_border", '\u{e87e}'),
+    ("favorite_outline", '\u{e87e}'),
+    ("featured_play_list", '\u{e06d}'),
+    ("featured_video", '\u{e06e}'),
+    ("feedback", '\u{e87f}'),
+    ("fiber_dvr", '\u{e05d}'),
+    ("fiber_manual_record", '\u{e061}'),
+    ("fiber_new", '\u{e05e}'),
+    ("fiber_pin", '\u{e06a}'),
+    ("fiber_smart_record", '\u{e062}'),
+    ("file_download", '\u{e2c4}'),
+    ("file_upload", '\u{e2c6}'),
+    ("filter", '\u{e3d3}'),
+    ("filter_1", '\u{e3d0}'),
+    ("filter_2", '\u{e3d1}'),
+    ("filter_3", '\u{e3d2}'),
+    ("filter_4", '\u{e3d4}'),
+    ("filter_5", '\u{e3d5}'),
+    ("filter_6", '\u{e3d6}'),
+    ("filter_7", '\u{e3d7}'),
+    ("filter_8", '\u{e3d8}'),
+    ("filter_9", '\u{e3d9}'),
+    ("filter_9_plus", '\u{e3da}'),
+    ("filter_b_and_w", '\u{e3db}'),
+    ("filter_center_focus", '\u{e3dc}'),
+    ("filter_drama", '\u{e3dd}'),
+    ("filter_frames", '\u{e3de}'),
+    ("filter_hdr", '\u{e3df}'),
+    ("filter_list", '\u{e152}'),
+    ("filter_none", '\u{e3e0}'),
+    ("filter_tilt_shift", '\u{e3e2}'),
+    ("filter_vintage", '\u{e3e3}'),
+    ("find_in_page", '\u{e880}'),
+    ("find_replace", '\u{e881}'),
+    ("fingerprint", '\u{e90d}'),
+    ("first_page", '\u{e5dc}'),
+    ("fitness_center", '\u{eb43}'),
+    ("flag", '\u{e153}'),
+    ("flare", '\u{e3e4}'),
+    ("flash_auto", '\u{e3e5}'),
+    ("flash_off", '\u{e3e6}'),
+    ("flash_on", '\u{e3e7}'),
+    ("flight", '\u{e539}'),
+    ("flight_land", '\u{e904}'),
+    ("flight_takeoff", '\u{e905}'),
+    ("flip", '\u{e3e8}'),
+    ("flip_to_back", '\u{e882}'),
+    ("flip_to_front", '\u{e883}'),
+    ("folder", '\u{e2c7}'),
+    ("folder_open", '\u{e2c8}'),
+    ("folder_shared", '\u{e2c9}'),
+    ("folder_special", '\u{e617}'),
+    ("font_download", '\u{e167}'),
+    ("format_align_center", '\u{e234}'),
+    ("format_align_justify", '\u{e235}'),
+    ("format_align_left", '\u{e236}'),
+    ("format_align_right", '\u{e237}'),
+    ("format_bold", '\u{e238}'),
+    ("format_clear", '\u{e239}'),
+    ("format_color_fill", '\u{e23a}'),
+    ("format_color_reset", '\u{e23b}'),
+    ("format_color_text", '\u{e23c}'),
+    ("format_indent_decrease", '\u{e23d}'),
+    ("format_indent_increase", '\u{e23e}'),
+    ("format_italic", '\u{e23f}'),
+    ("format_line_spacing", '\u{e240}'),
+    ("format_list_bulleted", '\u{e241}'),
+    ("format_list_numbered", '\u{e242}'),
+    ("format_paint", '\u{e243}'),
+    ("format_quote", '\u{e244}'),
+    ("format_shapes", '\u{e25e}'),
+    ("format_size", '\u{e245}'),
+    ("format_strikethrough", '\u{e246}'),
+    ("format_textdirection_l_to_r", '\u{e247}'),
+    ("format_textdirection_r_to_l", '\u{e248}'),
+    ("format_underline", '\u{e249}'),
+    ("format_underlined", '\u{e249}'),
+    ("forum", '\u{e0bf}'),
+    ("forward", '\u{e154}'),
+    ("forward_10", '\u{e056}'),
+    ("forward_30", '\u{e057}'),
+    ("forward_5", '\u{e058}'),
+    ("free_breakfast", '\u{eb44}'),
+    ("fullscreen", '\u{e5d0}'),
+    ("fullscreen_exit", '\u{e5d1}'),
+    ("functions", '\u{e24a}'),
+    ("g_translate", '\u{e927}'),
+    ("gamepad", '\u{e30f}'),
+    ("games", '\u{e021}'),
+    ("gavel", '\u{e90e}'),
+    ("gesture", '\u{e155}'),
+    ("get_app", '\u{e884}'),
+    ("gif", '\u{e908}'),
+    ("golf_course", '\u{eb45}'),
+    ("gps_fixed", '\u{e1b3}'),
+    ("gps_not_fixed", '\u{e1b4}'),
+    ("gps_off", '\u{e1b5}'),
+    ("grade", '\u{e885}'),
+    ("gradient", '\u{e3e9}'),
+    ("grain", '\u{e3ea}'),
+    ("graphic_eq", '\u{e1b8}'),
+    ("grid_off", '\u{e3eb}'),
+    ("grid_on", '\u{e3ec}'),
+    ("group", '\u{e7ef}'),
+    ("group_add", '\u{e7f0}'),
+    ("group_work", '\u{e886}'),
+    ("hd", '\u{e052}'),
+    ("hdr_off", '\u{e3ed}'),
+    ("hdr_on", '\u{e3ee}'),
+    ("hdr_strong", '\u{e3f1}'),
+    ("hdr_weak", '\u{e3f2}'),
+    ("headset", '\u{e310}'),
+    ("headset_mic", '\u{e311}'),
+    ("healing", '\u{e3f3}'),
+    ("hearing", '\u{e023}'),
+    ("help", '\u{e887}'),
+    ("help_outline", '\u{e8fd}'),
+    ("high_quality", '\u{e024}'),
+    ("highlight", '\u{e25f}'),
+    ("highlight_off", '\u{e888}'),
+    ("highlight_remove", '\u{e888}'),
+    ("history", '\u{e889}'),
+    ("home", '\u{e88a}'),
+    ("hot_tub", '\u{eb46}'),
+    ("hotel", '\u{e53a}'),
+    ("hourglass_empty", '\u{e88b}'),
+    ("hourglass_full", '\u{e88c}'),
+    ("http", '\u{e902}'),
+    ("https", '\u{e88d}'),
+    ("image", '\u{e3f4}'),
+    ("image_aspect_ratio", '\u{e3f5}'),
+    ("import_contacts", '\u{e0e0}'),
+    ("import_export", '\u{e0c3}'),
+    ("important_devices", '\u{e912}'),
+    ("inbox", '\u{e156}'),
+    ("indeterminate_check_box", '\u{e909}'),
+    ("info", '\u{e88e}'),
+    ("info_outline", '\u{e88f}'),
+    ("input", '\u{e890}'),
+    ("insert_chart", '\u{e24b}'),
+    ("insert_comment", '\u{e24c}'),
+    ("insert_drive_file", '\u{e24d}'),
+    ("insert_emoticon", '\u{e24e}'),
+    ("insert_invitation", '\u{e24f}'),
+    ("insert_link", '\u{e250}'),
+    ("insert_photo", '\u{e251}'),
+    ("invert_colors", '\u{e891}'),
+    ("invert_colors_off", '\u{e0c4}'),
+    ("invert_colors_on", '\u{e891}'),
+    ("iso", '\u{e3f6}'),
+    ("keyboard", '\u{e312}'),
+    ("keyboard_arrow_down", '\u{e313}'),
+    ("keyboard_arrow_left", '\u{e314}'),
+    ("keyboard_arrow_right", '\u{e315}'),
+    ("keyboard_arrow_up", '\u{e316}'),
+    ("keyboard_backspace", '\u{e317}'),
+    ("keyboard_capslock", '\u{e318}'),
+    ("keyboard_control", '\u{e5d3}'),
+    ("keyboard_hide", '\u{e31a}'),
+    ("keyboard_return", '\u{e31b}'),
+    ("keyboard_tab", '\u{e31c}'),
+    ("keyboard_voice", '\u{e31d}'),
+    ("kitchen", '\u{eb47}'),
+    ("label", '\u{e892}'),
+    ("label_outline", '\u{e893}'),
+    ("landscape", '\u{e3f7}'),
+    ("language", '\u{e894}'),
+    ("laptop", '\u{e31e}'),
+    ("laptop_chromebook", '\u{e31f}'),
+    ("laptop_mac", '\u{e320}'),
+    ("laptop_windows", '\u{e321}'),
+    ("last_page", '\u{e5dd}'),
+    ("launch", '\u{e895}'),
+    ("layers", '\u{e53b}'),
+    ("layers_clear", '\u{e53c}'),
+    ("leak_add", '\u{e3f8}'),
+    ("leak_remove", '\u{e3f9}'),
+    ("lens", '\u{e3fa}'),
+    ("library_add", '\u{e02e}'),
+    ("library_books", '\u{e02f}'),
+    ("library_music", '\u{e030}'),
+    ("lightbulb_outline", '\u{e90f}'),
+    ("line_style", '\u{e919}'),
+    ("line_weight", '\u{e91a}'),
+    ("linear_scale", '\u{e260}'),
+    ("link", '\u{e157}'),
+    ("linked_camera", '\u{e438}'),
+    ("list", '\u{e896}'),
+    ("live_help", '\u{e0c6}'),
+    ("live_tv", '\u{e639}'),
+    ("local_activity", '\u{e53f}'),
+    ("local_airport", '\u{e53d}'),
+    ("local_atm", '\u{e53e}'),
+    ("local_attraction", '\u{e53f}'),
+    ("local_bar", '\u{e540}'),
+    ("local_cafe", '\u{e541}'),
+    ("local_car_wash", '\u{e542}'),
+    ("local_convenience_store", '\u{e543}'),
+    ("local_dining", '\u{e556}'),
+    ("local_drink", '\u{e544}'),
+    ("local_florist", '\u{e545}'),
+    ("local_gas_station", '\u{e546}'),
+    ("local_grocery_store", '\u{e547}'),
+    ("local_hospital", '\u{e548}'),
+    ("local_hotel", '\u{e549}'),
+    ("local_laundry_service", '\u{e54a}'),
+    ("local_library", '\u{e54b}'),
+    ("local_mall", '\u{e54c}'),
+    ("local_movies", '\u{e54d}'),
+    ("local_offer", '\u{e54e}'),
+    ("local_parking", '\u{e54f}'),
+    ("local_pharmacy", '\u{e550}'),
+    ("local_phone", '\u{e551}'),
+    ("local_pizza", '\u{e552}'),
+    ("local_play", '\u{e553}'),
+    ("local_post_office", '\u{e554}'),
+    ("local_print_shop", '\u{e555}'),
+    ("local_printshop", '\u{e555}'),
+    ("local_restaurant", '\u{e556}'),
+    ("local_see", '\u{e557}'),
+    ("local_shipping", '\u{e558}'),
+    ("local_taxi", '\u{e559}'),
+    ("location_city", '\u{e7f1}'),
+    ("location_disabled", '\u{e1b6}'),
+    ("location_history", '\u{e55a}'),
+    ("location_off", '\u{e0c7}'),
+    ("location_on", '\u{e0c8}'),
+    ("location_searching", '\u{e1b7}'),
+    ("lock", '\u{e897}'),
+    ("lock_open", '\u{e898}'),
+    ("lock_outline", '\u{e899}'),
+    ("looks", '\u{e3fc}'),
+    ("looks_3", '\u{e3fb}'),
+    ("looks_4", '\u{e3fd}'),
+    ("looks_5", '\u{e3fe}'),
+    ("looks_6", '\u{e3ff}'),
+    ("looks_one", '\u{e400}'),
+    ("looks_two", '\u{e401}'),
+    ("loop", '\u{e028}'),
+    ("loupe", '\u{e402}'),
+    ("low_priority", '\u{e16d}'),
+    ("loyalty", '\u{e89a}'),
+    ("mail", '\u{e158}'),
+    ("mail_outline", '\u{e0e1}'),
+    ("map", '\u{e55b}'),
+    ("markunread", '\u{e159}'),
+    ("markunread_mailbox", '\u{e89b}'),
+    ("memory", '\u{e322}'),
+    ("menu", '\u{e5d2}'),
+    ("merge_type", '\u{e252}'),
+    ("message", '\u{e0c9}'),
+    ("messenger", '\u{e0ca}'),
+    ("messenger_outline", '\u{e0cb}'),
+    ("mic", '\u{e029}'),
+    ("mic_none", '\u{e02a}'),
+    ("mic_off", '\u{e02b}'),
+    ("mms", '\u{e618}'),
+    ("mode_comment", '\u{e253}'),
+    ("mode_edit", '\u{e254}'),
+    ("monetization_on", '\u{e263}'),
+    ("money_off", '\u{e25c}'),
+    ("monochrome_photos", '\u{e403}'),
+    ("mood", '\u{e7f2}'),
+    ("mood_bad", '\u{e7f3}'),
+    ("more", '\u{e619}'),
+    ("more_horiz", '\u{e5d3}'),
+    ("more_vert", '\u{e5d4}'),
+    ("motorcycle", '\u{e91b}'),
+    ("mouse", '\u{e323}'),
+    ("move_to_inbox", '\u{e168}'),
+    ("movie", '\u{e02c}'),
+    ("movie_creation", '\u{e404}'),
+    ("movie_filter", '\u{e43a}'),
+    ("multiline_chart", '\u{e6df}'),
+    ("multitrack_audio", '\u{e1b8}'),
+    ("music_note", '\u{e405}'),
+    ("music_video", '\u{e063}'),
+    ("my_library_add", '\u{e02e}'),
+    ("my_library_books", '\u{e02f}'),
+    ("my_library_music", '\u{e030}'),
+    ("my_location", '\u{e55c}'),
+    ("nature", '\u{e406}'),
+    ("nature_people", '\u{e407}'),
+    ("navigate_before", '\u{e408}'),
+    ("navigate_next", '\u{e409}'),
+    ("navigation", '\u{e55d}'),
+    ("near_me", '\u{e569}'),
+    ("network_cell", '\u{e1b9}'),
+    ("network_check", '\u{e640}'),
+    ("network_locked", '\u{e61a}'),
+    ("network_wifi", '\u{e1ba}'),
+    ("new_releases", '\u{e031}'),
+    ("next_week", '\u{e16a}'),
+    ("nfc", '\u{e1bb}'),
+    ("no_encryption", '\u{e641}'),
+    ("no_sim", '\u{e0cc}'),
+    ("not_interested", '\u{e033}'),
+    ("note", '\u{e06f}'),
+    ("note_add", '\u{e89c}'),
+    ("notifications", '\u{e7f4}'),
+    ("notifications_active", '\u{e7f7}'),
+    ("notifications_none", '\u{e7f5}'),
+    ("notifications_off", '\u{e7f6}'),
+    ("notifications_on", '\u{e7f7}'),
+    ("notifications_paused", '\u{e7f8}'),
+    ("now_wallpaper", '\u{e1bc}'),
+    ("now_widgets", '\u{e1bd}'),
+    ("offline_pin", '\u{e90a}'),
+    ("ondemand_video", '\u{e63a}'),
+    ("opacity", '\u{e91c}'),
+    ("open_in_browser", '\u{e89d}'),
+    ("open_in_new", '\u{e89e}'),
+    ("open_with", '\u{e89f}'),
+    ("pages", '\u{e7f9}'),
+    ("pageview", '\u{e8a0}'),
+    ("palette", '\u{e40a}'),
+    ("pan_tool", '\u{e925}'),
+    ("panorama", '\u{e40b}'),
+    ("panorama_fish_eye", '\u{e40c}'),
+    ("panorama_fisheye", '\u{e40c}'),
+    ("panorama_horizontal", '\u{e40d}'),
+    ("panorama_vertical", '\u{e40e}'),
+    ("panorama_wide_angle", '\u{e40f}'),
+    ("party_mode", '\u{e7fa}'),
+    ("pause", '\u{e034}'),
+    ("pause_circle_filled", '\u{e035}'),
+    ("pause_circle_outline", '\u{e036}'),
+    ("payment", '\u{e8a1}'),
+    ("people", '\u{e7fb}'),
+    ("people_outline", '\u{e7fc}'),
+    ("perm_camera_mic", '\u{e8a2}'),
+    ("perm_contact_cal", '\u{e8a3}'),
+    ("perm_contact_calendar", '\u{e8a3}'),
+    ("perm_data_setting", '\u{e8a4}'),
+    ("perm_device_info", '\u{e8a5}'),
+    ("perm_device_information", '\u{e8a5}'),
+    ("perm_identity", '\u{e8a6}'),
+    ("perm_media", '\u{e8a7}'),
+    ("perm_phone_msg", '\u{e8a8}'),
+    ("perm_scan_wifi", '\u{e8a9}'),
+    ("person", '\u{e7fd}'),
+    ("person_add", '\u{e7fe}'),
+    ("person_outline", '\u{e7ff}'),
+    ("person_pin", '\u{e55a}'),
+    ("person_pin_circle", '\u{e56a}'),
+    ("personal_video", '\u{e63b}'),
+    ("pets", '\u{e91d}'),
+    ("phone", '\u{e0cd}'),
+    ("phone_android", '\u{e324}'),
+    ("phone_bluetooth_speaker", '\u{e61b}'),
+    ("phone_forwarded", '\u{e61c}'),
+    ("phone_in_talk", '\u{e61d}'),
+    ("phone_iphone", '\u{e325}'),
+    ("phone_locked", '\u{e61e}'),
+    ("phone_missed", '\u{e61f}'),
+    ("phone_paused", '\u{e620}'),
+    ("phonelink", '\u{e326}'),
+    ("phonelink_erase", '\u{e0db}'),
+    ("phonelink_lock", '\u{e0dc}'),
+    ("phonelink_off", '\u{e327}'),
+    ("phonelink_ring", '\u{e0dd}'),
+    ("phonelink_setup", '\u{e0de}'),
+    ("photo", '\u{e410}'),
+    ("photo_album", '\u{e411}'),
+    ("photo_camera", '\u{e412}'),
+    ("photo_filter", '\u{e43b}'),
+    ("photo_library", '\u{e413}'),
+    ("photo_size_select_actual", '\u{e432}'),
+    ("photo_size_select_large", '\u{e433}'),
+    ("photo_size_select_small", '\u{e434}'),
+    ("picture_as_pdf", '\u{e415}'),
+    ("picture_in_picture", '\u{e8aa}'),
+    ("picture_in_picture_alt", '\u{e911}'),
+    ("pie_chart", '\u{e6c4}'),
+    ("pie_chart_outlined", '\u{e6c5}'),
+    ("pin_drop", '\u{e55e}'),
+    ("place", '\u{e55f}'),
+    ("play_arrow", '\u{e037}'),
+    ("play_circle_fill", '\u{e038}'),
+    ("play_circle_filled", '\u{e038}'),
+    ("play_circle_outline", '\u{e039}'),
+    ("play_for_work", '\u{e906}'),
+    ("playlist_add", '\u{e03b}'),
+    ("playlist_add_check", '\u{e065}'),
+    ("playlist_play", '\u{e05f}'),
+    ("plus_one", '\u{e800}'),
+    ("poll", '\u{e801}'),
+    ("polymer", '\u{e8ab}'),
+    ("pool", '\u{eb48}'),
+    ("portable_wifi_off", '\u{e0ce}'),
+    ("portrait", '\u{e416}'),
+    ("power", '\u{e63c}'),
+    ("power_input", '\u{e336}'),
+    ("power_settings_new", '\u{e8ac}'),
+    ("pregnant_woman", '\u{e91e}'),
+    ("present_to_all", '\u{e0df}'),
+    ("print", '\u{e8ad}'),
+    ("priority_high", '\u{e645}'),
+    ("public", '\u{e80b}'),
+    ("publish", '\u{e255}'),
+    ("query_builder", '\u{e8ae}'),
+    ("question_answer", '\u{e8af}'),
+    ("queue", '\u{e03c}'),
+    ("queue_music", '\u{e03d}'),
+    ("queue_play_next", '\u{e066}'),
+    ("quick_contacts_dialer", '\u{e0cf}'),
+    ("quick_contacts_mail", '\u{e0d0}'),
+    ("radio", '\u{e03e}'),
+    ("radio_button_checked", '\u{e837}'),
+    ("radio_button_off", '\u{e836}'),
+    ("radio_button_on", '\u{e837}'),
+    ("radio_button_unchecked", '\u{e836}'),
+    ("rate_review", '\u{e560}'),
+    ("receipt", '\u{e8b0}'),
+    ("recent_actors", '\u{e03f}'),
+    ("record_voice_over", '\u{e91f}'),
+    ("redeem", '\u{e8b1}'),
+    ("redo", '\u{e15a}'),
+    ("refresh", '\u{e5d5}'),
+    ("remove", '\u{e15b}'),
+    ("remove_circle", '\u{e15c}'),
+    ("remove_circle_outline", '\u{e15d}'),
+    ("remove_from_queue", '\u{e067}'),
+    ("remove_red_eye", '\u{e417}'),
+    ("remove_shopping_cart", '\u{e928}'),
+    ("reorder", '\u{e8fe}'),
+    ("repeat", '\u{e040}'),
+    ("repeat_one", '\u{e041}'),
+    ("replay", '\u{e042}'),
+    ("replay_10", '\u{e059}'),
+    ("replay_30", '\u{e05a}'),
+    ("replay_5", '\u{e05b}'),
+    ("reply", '\u{e15e}'),
+    ("reply_all", '\u{e15f}'),
+    ("report", '\u{e160}'),
+    ("report_problem", '\u{e8b2}'),
+    ("restaurant", '\u{e56c}'),
+    ("restaurant_menu", '\u{e561}'),
+    ("restore", '\u{e8b3}'),
+    ("restore_page", '\u{e929}'),
+    ("ring_volume", '\u{e0d1}'),
+    ("room", '\u{e8b4}'),
+    ("room_service", '\u{eb49}'),
+    ("rotate_90_degrees_ccw", '\u{e418}'),
+    ("rotate_left", '\u{e419}'),
+    ("rotate_right", '\u{e41a}'),
+    ("rounded_corner", '\u{e920}'),
+    ("router", '\u{e328}'),
+    ("rowing", '\u{e921}'),
+    ("rss_feed", '\u{e0e5}'),
+    ("rv_hookup", '\u{e642}'),
+    ("satellite", '\u{e562}'),
+    ("save", '\u{e161}'),
+    ("scanner", '\u{e329}'),
+    ("schedule", '\u{e8b5}'),
+    ("school", '\u{e80c}'),
+    ("screen_lock_landscape", '\u{e1be}'),
+    ("screen_lock_portrait", '\u{e1bf}'),
+    ("screen_lock_rotation", '\u{e1c0}'),
+    ("screen_rotation", '\u{e1c1}'),
+    ("screen_share", '\u{e0e2}'),
+    ("sd_card", '\u{e623}'),
+    ("sd_storage", '\u{e1c2}'),
+    ("search", '\u{e8b6}'),
+    ("security", '\u{e32a}'),
+    ("select_all", '\u{e162}'),
+    ("send", '\u{e163}'),
+    ("sentiment_dissatisfied", '\u{e811}'),
+    ("sentiment_neutral", '\u{e812}'),
+    ("sentiment_satisfied", '\u{e813}'),
+    ("sentiment_very_dissatisfied", '\u{e814}'),
+    ("sentiment_very_satisfied", '\u{e815}'),
+    ("settings", '\u{e8b8}'),
+    ("settings_applications", '\u{e8b9}'),
+    ("settings_backup_restore", '\u{e8ba}'),
+    ("settings_bluetooth", '\u{e8bb}'),
+    ("settings_brightness", '\u{e8bd}'),
+    ("settings_cell", '\u{e8bc}'),
+    ("settings_display", '\u{e8bd}'),
+    ("settings_ethernet", '\u{e8be}'),
+    ("settings_input_antenna", '\u{e8bf}'),
+    ("settings_input_component", '\u{e8c0}'),
+    ("settings_input_composite", '\u{e8c1}'),
+    ("settings_input_hdmi", '\u{e8c2}'),
+    ("settings_input_svideo", '\u{e8c3}'),
+    ("settings_overscan", '\u{e8c4}'),
+    ("settings_phone", '\u{e8c5}'),
+    ("settings_power", '\u{e8c6}'),
+    ("settings_remote", '\u{e8c7}'),
+    ("settings_system_daydream", '\u{e1c3}'),
+    ("settings_voice", '\u{e8c8}'),
+    ("share", '\u{e80d}'),
+    ("shop", '\u{e8c9}'),
+    ("shop_two", '\u{e8ca}'),
+    ("shopping_basket", '\u{e8cb}'),
+    ("shopping_cart", '\u{e8cc}'),
+    ("short_text", '\u{e261}'),
+    ("show_chart", '\u{e6e1}'),
+    ("shuffle", '\u{e043}'),
+    ("signal_cellular_4_bar", '\u{e1c8}'),
+    ("signal_cellular_connected_no_internet_4_bar", '\u{e1cd}'),
+    ("signal_cellular_no_sim", '\u{e1ce}'),
+    ("signal_cellular_null", '\u{e1cf}'),
+    ("signal_cellular_off", '\u{e1d0}'),
+    ("signal_wifi_4_bar", '\u{e1d8}'),
+    ("signal_wifi_4_bar_lock", '\u{e1d9}'),
+    ("signal_wifi_off", '\u{e1da}'),
+    ("sim_card", '\u{e32b}'),
+    ("sim_card_alert", '\u{e624}'),
+    ("skip_next", '\u{e044}'),
+    ("skip_previous", '\u{e045}'),
+    ("slideshow", '\u{e41b}'),
+    ("slow_motion_video", '\u{e068}'),
+    ("smartphone", '\u{e32c}'),
+    ("smoke_free", '\u{eb4a}'),
+    ("smoking_rooms", '\u{eb4b}'),
+    ("sms", '\u{e625}'),
+    ("sms_failed", '\u{e626}'),
+    ("snooze", '\u{e046}'),
+    ("sort", '\u{e164}'),
+    ("sort_by_alpha", '\u{e053}'),
+    ("spa", '\u{eb4c}'),
+    ("space_bar", '\u{e256}'),
+    ("speaker", '\u{e32d}'),
+    ("speaker_group", '\u{e32e}'),
+    ("speaker_notes", '\u{e8cd}'),
+    ("speaker_notes_off", '\u{e92a}'),
+    ("speaker_phone", '\u{e0d2}'),
+    ("spellcheck", '\u{e8ce}'),
+    ("star", '\u{e838}'),
+    ("star_border", '\u{e83a}'),
+    ("star_half", '\u{e839}'),
+    ("star_outline", '\u{e83a}'),
+    ("stars", '\u{e8d0}'),
+    ("stay_current_landscape", '\u{e0d3}'),
+    ("stay_current_portrait", '\u{e0d4}'),
+    ("stay_primary_landscape", '\u{e0d5}'),
+    ("stay_primary_portrait", '\u{e0d6}'),
+    ("stop", '\u{e047}'),
+    ("stop_screen_share", '\u{e0e3}'),
+    ("storage", '\u{e1db}'),
+    ("store", '\u{e8d1}'),
+    ("store_mall_directory", '\u{e563}'),
+    ("straighten", '\u{e41c}'),
+    ("streetview", '\u{e56e}'),
+    ("strikethrough_s", '\u{e257}'),
+    ("style", '\u{e41d}'),
+    ("subdirectory_arrow_left", '\u{e5d9}'),
+    ("subdirectory_arrow_right", '\u{e5da}'),
+    ("subject", '\u{e8d2}'),
+    ("subscriptions", '\u{e064}'),
+    ("subtitles", '\u{e048}'),
+    ("subway", '\u{e56f}'),
+    ("supervisor_account", '\u{e8d3}'),
+    ("surround_sound", '\u{e049}'),
+    ("swap_calls", '\u{e0d7}'),
+    ("swap_horiz", '\u{e8d4}'),
+    ("swap_vert", '\u{e8d5}'),
+    ("swap_vert_circle", '\u{e8d6}'),
+    ("swap_vertical_circle", '\u{e8d6}'),
+    ("switch_camera", '\u{e41e}'),
+    ("switch_video", '\u{e41f}'),
+    ("sync", '\u{e627}'),
+    ("sync_disabled", '\u{e628}'),
+    ("sync_problem", '\u{e629}'),
+    ("system_update", '\u{e62a}'),
+    ("system_update_alt", '\u{e8d7}'),
+    ("system_update_tv", '\u{e8d7}'),
+    ("tab", '\u{e8d8}'),
+    ("tab_unselected", '\u{e8d9}'),
+    ("tablet", '\u{e32f}'),
+    ("tablet_android", '\u{e330}'),
+    ("tablet_mac", '\u{e331}'),
+    ("tag_faces", '\u{e420}'),
+    ("tap_and_play", '\u{e62b}'),
+    ("terrain", '\u{e564}'),
+    ("text_fields", '\u{e262}'),
+    ("text_format", '\u{e165}'),
+    ("textsms", '\u{e0d8}'),
+    ("texture", '\u{e421}'),
+    ("theaters", '\u{e8da}'),
+    ("thumb_down", '\u{e8db}'),
+    ("thumb_up", '\u{e8dc}'),
+    ("thumbs_up_down", '\u{e8dd}'),
+    ("time_to_leave", '\u{e62c}'),
+    ("timelapse", '\u{e422}'),
+    ("timeline", '\u{e922}'),
+    ("timer", '\u{e425}'),
+    ("timer_10", '\u{e423}'),
+    ("timer_3", '\u{e424}'),
+    ("timer_off", '\u{e426}'),
+    ("title", '\u{e264}'),
+    ("toc", '\u{e8de}'),
+    ("today", '\u{e8df}'),
+    ("toll", '\u{e8e0}'),
+    ("tonality", '\u{e427}'),
+    ("touch_app", '\u{e913}'),
+    ("toys", '\u{e332}'),
+    ("track_changes", '\u{e8e1}'),
+    ("traffic", '\u{e565}'),
+    ("train", '\u{e570}'),
+    ("tram", '\u{e571}'),
+    ("transfer_within_a_station", '\u{e572}'),
+    ("transform", '\u{e428}'),
+    ("translate", '\u{e8e2}'),
+    ("trending_down", '\u{e8e3}'),
+    ("trending_flat", '\u{e8e4}'),
+    ("trending_neutral", '\u{e8e4}'),
+    ("trending_up", '\u{e8e5}'),
+    ("tune", '\u{e429}'),
+    ("turned_in", '\u{e8e6}'),
+    ("turned_in_not", '\u{e8e7}'),
+    ("tv", '\u{e333}'),
+    ("unarchive", '\u{e169}'),
+    ("undo", '\u{e166}'),
+    ("unfold_less", '\u{e5d6}'),
+    ("unfold_more", '\u{e5d7}'),
+    ("update", '\u{e923}'),
+    ("usb", '\u{e1e0}'),
+    ("verified_user", '\u{e8e8}'),
+    ("vertical_align_bottom", '\u{e258}'),
+    ("vertical_align_center", '\u{e259}'),
+    ("vertical_align_top", '\u{e25a}'),
+    ("vibration", '\u{e62d}'),
+    ("video_call", '\u{e070}'),
+    ("video_collection", '\u{e04a}'),
+    ("video_label", '\u{e071}'),
+    ("video_library", '\u{e04a}'),
+    ("videocam", '\u{e04b}'),
+    ("videocam_off", '\u{e04c}'),
+    ("videogame_asset", '\u{e338}'),
+    ("view_agenda", '\u{e8e9}'),
+    ("view_array", '\u{e8ea}'),
+    ("view_carousel", '\u{e8eb}'),
+    ("view_column", '\u{e8ec}'),
+    ("view_comfortable", '\u{e42a}'),
+    ("view_comfy", '\u{e42a}'),
+    ("view_compact", '\u{e42b}'),
+    ("view_day", '\u{e8ed}'),
+    ("view_headline", '\u{e8ee}'),
+    ("view_list", '\u{e8ef}'),
+    ("view_module", '\u{e8f0}'),
+    ("view_quilt", '\u{e8f1}'),
+    ("view_stream", '\u{e8f2}'),
+    ("view_week", '\u{e8f3}'),
+    ("vignette", '\u{e435}'),
+    ("visibility", '\u{e8f4}'),
+    ("visibility_off", '\u{e8f5}'),
+    ("voice_chat", '\u{e62e}'),
+    ("voicemail", '\u{e0d9}'),
+    ("volume_down", '\u{e04d}'),
+    ("volume_mute", '\u{e04e}'),
+    ("volume_off", '\u{e04f}'),
+    ("volume_up", '\u{e050}'),
+    ("vpn_key", '\u{e0da}'),
+    ("vpn_lock", '\u{e62f}'),
+    ("wallet_giftcard", '\u{e8f6}'),
+    ("wallet_membership", '\u{e8f7}'),
+    ("wallet_travel", '\u{e8f8}'),
+    ("wallpaper", '\u{e1bc}'),
+    ("warning", '\u{e002}'),
+    ("watch", '\u{e334}'),
+    ("watch_later", '\u{e924}'),
+    ("wb_auto", '\u{e42c}'),
+    ("wb_cloudy", '\u{e42d}'),
+    ("wb_incandescent", '\u{e42e}'),
+    ("wb_iridescent", '\u{e436}'),
+    ("wb_sunny", '\u{e430}'),
+    ("wc", '\u{e63d}'),
+    ("web", '\u{e051}'),
+    ("web_asset", '\u{e069}'),
+    ("weekend", '\u{e16b}'),
+    ("whatshot", '\u{e80e}'),
+    ("widgets", '\u{e1bd}'),
+    ("wifi", '\u{e63e}'),
+    ("wifi_lock", '\u{e1e1}'),
+    ("wifi_tethering", '\u{e1e2}'),
+    ("work", '\u{e8f9}'),
+    ("wrap_text", '\u{e25b}'),
+    ("youtube_searched_for", '\u{e8fa}'),
+    ("zoom_in", '\u{e8ff}'),
+    ("zoom_out", '\u{e900}'),
+    ("zoom_out_map", '\u{e56b}'),
+];
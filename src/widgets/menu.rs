@@ -0,0 +1,343 @@
+use crate::input::{Key, PointerButton};
+use crate::ui::{ElementHandle, UIBuilder, Widget};
+
+pub const MENU_BAR_COLOR: (f32, f32, f32, f32) = (0.2, 0.2, 0.2, 1.0);
+pub const MENU_COLOR: (f32, f32, f32, f32) = (0.15, 0.15, 0.15, 1.0);
+pub const MENU_BORDER_COLOR: (f32, f32, f32, f32) = (0.4, 0.4, 0.4, 1.0);
+pub const MENU_HIGHLIGHT_COLOR: (f32, f32, f32, f32) = (0.3, 0.45, 0.8, 1.0);
+pub const MENU_DISABLED_TEXT_COLOR: (f32, f32, f32, f32) = (1.0, 1.0, 1.0, 0.4);
+pub const MENU_SHORTCUT_TEXT_COLOR: (f32, f32, f32, f32) = (1.0, 1.0, 1.0, 0.6);
+pub const MENU_TEXT_SIZE: f32 = 24.;
+/// The width of items in a menu popup.
+pub const MENU_WIDTH: f32 = 320.;
+/// The width kept for shortcut hints on the right of items, so long text stops before them.
+pub const MENU_HINT_WIDTH: f32 = 130.;
+/// The space on either side of the text in a menu popup.
+const MENU_ITEM_PADDING: f32 = 20.;
+
+#[derive(Copy, Clone, PartialEq)]
+enum MenuKind {
+    Bar,
+    Popup,
+}
+
+/// A menu bar or an open menu that menus, items and separators can be added to.
+pub struct Menu<'a> {
+    builder: UIBuilder<'a>,
+    id: u64,
+    kind: MenuKind,
+}
+
+/// The state shared by menu bars, menus and context menus.
+struct MenuState {
+    /// The menu that's currently open within this menu.
+    open_child: Option<u64>,
+    /// Context menus are open until they're dismissed.
+    open: bool,
+    /// Where a context menu was opened.
+    position: (f32, f32),
+    /// The element that opens the menu, or the area a context menu is for.
+    anchor: Option<ElementHandle>,
+}
+
+impl MenuState {
+    fn new() -> Self {
+        Self {
+            open_child: None,
+            open: false,
+            position: (0., 0.),
+            anchor: None,
+        }
+    }
+}
+
+impl Widget for MenuState {}
+
+struct MenuItem {
+    element: Option<ElementHandle>,
+    /// The pointer was pressed on the item, so releasing on it chooses it.
+    pressed: bool,
+}
+
+impl Widget for MenuItem {}
+
+fn get_state(parent: &UIBuilder, id: u64) -> Box<MenuState> {
+    parent
+        .get_widget(id)
+        .1
+        .unwrap_or_else(|| Box::new(MenuState::new()))
+}
+
+/// Returns true if the pointer was pressed somewhere that isn't a popup or the element.
+fn pointer_down_elsewhere(parent: &UIBuilder, element: Option<ElementHandle>) -> bool {
+    element.is_some_and(|e| parent.pointer_down_outside(e)) && !parent.pointer_in_popup()
+}
+
+/// Builds the popup that holds a menu's items.
+fn menu_popup<'a>(parent: &UIBuilder<'a>, id: u64, position: (f32, f32)) -> Menu<'a> {
+    let builder = parent
+        .popup(position.0, position.1)
+        .fill(MENU_BORDER_COLOR)
+        .padding(1.)
+        .fill(MENU_COLOR)
+        .padding_vertical(5.)
+        .text_size(MENU_TEXT_SIZE)
+        .column();
+    Menu {
+        builder,
+        id,
+        kind: MenuKind::Popup,
+    }
+}
+
+/// A row in a menu popup with text on the left and a hint on the right.
+fn menu_row<'a>(
+    menu: &Menu<'a>,
+    text: &str,
+    hint: &str,
+    highlighted: bool,
+    enabled: bool,
+) -> UIBuilder<'a> {
+    let (background, text_color) = match (highlighted, enabled) {
+        (_, false) => ((0., 0., 0., 0.), MENU_DISABLED_TEXT_COLOR),
+        (true, true) => (MENU_HIGHLIGHT_COLOR, (1., 1., 1., 1.)),
+        (false, true) => ((0., 0., 0., 0.), (1., 1., 1., 1.)),
+    };
+    let hint_width = if hint.is_empty() { 0. } else { MENU_HINT_WIDTH };
+    let row = menu.builder.fit();
+    let content = row
+        .width(MENU_WIDTH)
+        .fill(background)
+        .padding_horizontal(MENU_ITEM_PADDING)
+        .padding_vertical(8.)
+        .row();
+    content
+        .width(MENU_WIDTH - MENU_ITEM_PADDING * 2. - hint_width)
+        .text_color(text_color)
+        .ellipsis()
+        .text(text);
+    content
+        .width(hint_width)
+        .reverse_row()
+        .text_color(MENU_SHORTCUT_TEXT_COLOR)
+        .text(hint);
+    row
+}
+
+/// Create a bar that menus can be added to.
+pub fn menu_bar_with_id<'a>(parent: &UIBuilder<'a>, id: u64) -> Menu<'a> {
+    let mut state = get_state(parent, id);
+
+    // Escape, clicking elsewhere or choosing an item closes the open menu.
+    if parent.key_pressed(Key::Escape)
+        || parent.closing_popups()
+        || pointer_down_elsewhere(parent, state.anchor)
+    {
+        state.open_child = None;
+    }
+
+    // The bar is as wide as the parent and as tall as its titles.
+    let bar = parent.fit();
+    state.anchor = Some(bar.handle());
+    parent.add_widget(id, state);
+
+    Menu {
+        builder: bar
+            .horizontal_expander()
+            .fill(MENU_BAR_COLOR)
+            .text_size(MENU_TEXT_SIZE)
+            .row(),
+        id,
+        kind: MenuKind::Bar,
+    }
+}
+
+/// Create a bar that menus can be added to.
+#[track_caller]
+pub fn menu_bar<'a>(parent: &UIBuilder<'a>) -> Menu<'a> {
+    let id = super::calculate_id(());
+    menu_bar_with_id(parent, id)
+}
+
+/// Add a menu to a menu bar, or a submenu to a menu.
+/// Menus in a bar open when clicked and submenus open when hovered.
+/// Returns the open menu to add items to, or `None` if it's closed.
+pub fn menu_with_id<'a>(parent: &Menu<'a>, id: u64, title: &str) -> Option<Menu<'a>> {
+    let builder = &parent.builder;
+    let mut parent_state = get_state(builder, parent.id);
+    let mut state = get_state(builder, id);
+
+    // Input
+    if let Some(anchor) = state.anchor {
+        let hovered = builder.pointer_in_element(anchor);
+        let open = parent_state.open_child == Some(id);
+        match parent.kind {
+            MenuKind::Bar => {
+                if builder.pointer_down() && hovered {
+                    parent_state.open_child = if open { None } else { Some(id) };
+                } else if hovered && parent_state.open_child.is_some() {
+                    // Move between menus in the bar once one is open.
+                    parent_state.open_child = Some(id);
+                }
+            }
+            MenuKind::Popup => {
+                if hovered {
+                    parent_state.open_child = Some(id);
+                }
+            }
+        }
+    }
+    let open = parent_state.open_child == Some(id);
+    if !open {
+        state.open_child = None;
+    }
+
+    // Rendering
+    // The menu opens next to where its title was last frame.
+    let anchor_rectangle = state.anchor.map(|a| builder.element_rectangle(a));
+    let anchor = match parent.kind {
+        MenuKind::Bar => {
+            let background = if open {
+                MENU_HIGHLIGHT_COLOR
+            } else {
+                (0., 0., 0., 0.)
+            };
+            let title_root = builder.fit();
+            title_root
+                .fill(background)
+                .padding_horizontal(20.)
+                .padding_vertical(10.)
+                .text(title);
+            title_root
+        }
+        MenuKind::Popup => menu_row(parent, title, "›", open, true),
+    };
+    state.anchor = Some(anchor.handle());
+
+    builder.add_widget(parent.id, parent_state);
+    builder.add_widget(id, state);
+
+    let position = match (parent.kind, anchor_rectangle) {
+        (MenuKind::Bar, Some(r)) => (r.x, r.y + r.height),
+        // Submenus are aligned so their first item is next to the title.
+        (MenuKind::Popup, Some(r)) => (r.x + r.width, r.y - 6.),
+        (_, None) => return None,
+    };
+    if open {
+        Some(menu_popup(builder, id, position))
+    } else {
+        None
+    }
+}
+
+/// Add a menu to a menu bar, or a submenu to a menu.
+/// Returns the open menu to add items to, or `None` if it's closed.
+/// Uses the title for ID calculation.
+#[track_caller]
+pub fn menu<'a>(parent: &Menu<'a>, title: &str) -> Option<Menu<'a>> {
+    let id = super::calculate_id(title);
+    menu_with_id(parent, id, title)
+}
+
+/// Add an item to a menu with an optional shortcut hint, like "Ctrl+S".
+/// Disabled items are faded and can't be chosen.
+/// Returns true if the item was chosen, which also closes the menu.
+pub fn menu_item_with_id(
+    menu: &Menu,
+    id: u64,
+    text: &str,
+    shortcut: Option<&str>,
+    enabled: bool,
+) -> bool {
+    let builder = &menu.builder;
+    let mut item = builder.get_widget(id).1.unwrap_or_else(|| {
+        Box::new(MenuItem {
+            element: None,
+            pressed: false,
+        })
+    });
+
+    let hovered = item.element.is_some_and(|e| builder.pointer_in_element(e));
+    let mut chosen = false;
+    if hovered {
+        // Hovering an item closes any submenu open next to it.
+        let mut menu_state = get_state(builder, menu.id);
+        menu_state.open_child = None;
+        builder.add_widget(menu.id, menu_state);
+
+        if builder.pointer_down() {
+            item.pressed = true;
+        }
+        // Releasing the press that opened the menu over an item doesn't choose it.
+        if enabled && item.pressed && builder.pointer_up() {
+            chosen = true;
+            builder.request_close_popups();
+        }
+    }
+    if builder.pointer_up() {
+        item.pressed = false;
+    }
+
+    let row = menu_row(menu, text, shortcut.unwrap_or(""), hovered, enabled);
+    item.element = Some(row.handle());
+    builder.add_widget(id, item);
+    chosen
+}
+
+/// Add an item to a menu with an optional shortcut hint, like "Ctrl+S".
+/// Returns true if the item was chosen.
+/// Uses the text for ID calculation.
+#[track_caller]
+pub fn menu_item(menu: &Menu, text: &str, shortcut: Option<&str>, enabled: bool) -> bool {
+    let id = super::calculate_id(text);
+    menu_item_with_id(menu, id, text, shortcut, enabled)
+}
+
+/// Add a line between groups of items in a menu.
+pub fn menu_separator(menu: &Menu) {
+    menu.builder
+        .padding_vertical(5.)
+        .width(MENU_WIDTH)
+        .height(1.)
+        .fill(MENU_BORDER_COLOR);
+}
+
+/// Open a menu at the pointer when the secondary button is pressed over the area.
+/// The area should be the size of the element, for example a `fit`.
+/// Returns the open menu to add items to, or `None` if it's closed.
+pub fn context_menu_with_id<'a>(area: &UIBuilder<'a>, id: u64) -> Option<Menu<'a>> {
+    let mut state = get_state(area, id);
+
+    if area.key_pressed(Key::Escape)
+        || area.closing_popups()
+        || (area.pointer_button_down(PointerButton::Secondary) || area.pointer_down())
+            && !area.pointer_in_popup()
+    {
+        state.open = false;
+        state.open_child = None;
+    }
+    if let Some(anchor) = state.anchor {
+        if area.pointer_button_down(PointerButton::Secondary) && area.pointer_in_element(anchor) {
+            state.open = true;
+            state.position = area.pointer_position();
+        }
+    }
+
+    state.anchor = Some(area.handle());
+    let (open, position) = (state.open, state.position);
+    area.add_widget(id, state);
+
+    if open {
+        Some(menu_popup(area, id, position))
+    } else {
+        None
+    }
+}
+
+/// Open a menu at the pointer when the secondary button is pressed over the area.
+/// Returns the open menu to add items to, or `None` if it's closed.
+#[track_caller]
+pub fn context_menu<'a>(area: &UIBuilder<'a>) -> Option<Menu<'a>> {
+    let id = super::calculate_id(());
+    context_menu_with_id(area, id)
+}
@@ -1,18 +1,131 @@
 mod button;
+mod checkbox;
+mod collapsing;
+mod color_picker;
+mod dialog;
 mod drag;
+mod dropdown;
+mod horizontal_divider;
+mod icon;
 mod interpolation;
+mod list_view;
+mod material_icons;
+mod menu;
+mod number_field;
+mod progress_bar;
+mod radio_group;
 mod scroll_view;
+mod slider;
+mod table;
+mod tabs;
 mod text_field;
-//mod slider;
-mod horizontal_divider;
+mod toggle;
+mod tooltip;
+mod tree_view;
 mod vertical_divider;
 pub use button::*;
+pub use checkbox::*;
+pub use collapsing::*;
+pub use color_picker::*;
+pub use dialog::*;
+pub use dropdown::*;
 pub use horizontal_divider::*;
+pub use icon::*;
+pub use list_view::*;
+pub use menu::*;
+pub use number_field::*;
+pub use progress_bar::*;
+pub use radio_group::*;
 pub use scroll_view::*;
-//pub use slider::*;
+pub use slider::*;
+pub use table::*;
+pub use tabs::*;
 pub use text_field::*;
+pub use toggle::*;
+pub use tooltip::*;
+pub use tree_view::*;
 pub use vertical_divider::*;
 
+use crate::input::Key;
+use crate::ui::{ElementHandle, UIBuilder};
+use interpolation::*;
+
+pub const FOCUS_RING_COLOR: (f32, f32, f32, f32) = (0.3, 0.55, 1.0, 1.0);
+pub const FOCUS_RING_WIDTH: f32 = 3.;
+
+/// Draws a ring around the content when focused.
+/// The space for the ring is always reserved so that focusing doesn't shift the layout.
+fn focus_ring<'a>(parent: &UIBuilder<'a>, focused: bool) -> UIBuilder<'a> {
+    let color = if focused {
+        FOCUS_RING_COLOR
+    } else {
+        (0., 0., 0., 0.)
+    };
+    parent.fill(color).padding(FOCUS_RING_WIDTH)
+}
+
+/// Returns true if the widget with the ID is focused and Enter or Space was pressed.
+/// Holding the key down doesn't press the widget again.
+fn pressed_with_keyboard(parent: &UIBuilder, id: u64) -> bool {
+    parent.is_focused(id)
+        && (parent.key_pressed_without_repeat(Key::Enter)
+            || parent.key_pressed_without_repeat(Key::Space))
+}
+
+/// Input and animation shared by widgets that are checked or unchecked,
+/// like checkboxes, toggles and the options of a radio group.
+struct Checkable {
+    /// The pointer was pressed on the element and hasn't been released.
+    pointer_held_down: bool,
+    /// 0. to 1. How checked the widget appears.
+    animate: Interpolate,
+}
+
+impl Checkable {
+    fn new(checked: bool) -> Self {
+        let mut animate = Interpolate::new(InterpolationCurve::Ease);
+        animate.set(if checked { 1. } else { 0. });
+        Self {
+            pointer_held_down: false,
+            animate,
+        }
+    }
+
+    /// Returns true if the pointer was pressed and then released on the element.
+    /// Pressing the element focuses the widget with the ID so the keyboard can toggle it.
+    fn clicked(&mut self, parent: &UIBuilder, id: u64, element: Option<ElementHandle>) -> bool {
+        let pointer_in_element = element.is_some_and(|e| parent.pointer_in_element(e));
+        if parent.pointer_down() && pointer_in_element {
+            parent.request_focus(id);
+            self.pointer_held_down = true;
+        }
+        // A press taken by something else, like a scroll view scrolling, is cancelled.
+        if parent.pointer_captured() {
+            self.pointer_held_down = false;
+        }
+        let clicked = self.pointer_held_down && parent.pointer_up() && pointer_in_element;
+        if parent.pointer_up() {
+            self.pointer_held_down = false;
+        }
+        clicked
+    }
+
+    /// Animates towards checked or unchecked over `animation_time` milliseconds.
+    /// Returns 0. to 1. for how checked the widget should appear.
+    fn animate(&mut self, parent: &UIBuilder, checked: bool, animation_time: f32) -> f32 {
+        let delta = parent.animation_delta() / animation_time;
+        if checked {
+            self.animate.add(delta);
+        } else {
+            self.animate.subtract(delta);
+        }
+        if self.animate.not_one_or_zero() {
+            parent.request_animation_frame();
+        }
+        self.animate.get()
+    }
+}
+
 #[track_caller]
 /// Pass in extra data that will be hashed together with the location the widget function is called from
 fn calculate_id<T: std::hash::Hash>(t: T) -> u64 {
@@ -0,0 +1,235 @@
+use super::drag::Drag;
+use crate::ui::{ElementHandle, UIBuilder, Widget};
+
+pub const NUMBER_FIELD_ERROR_COLOR: (f32, f32, f32, f32) = (0.9, 0.2, 0.2, 1.0);
+pub const SCRUB_HANDLE_COLOR: (f32, f32, f32, f32) = (0.25, 0.25, 0.25, 1.0);
+/// The width of the text field between the steppers.
+pub const NUMBER_FIELD_WIDTH: f32 = 200.;
+/// How far the pointer moves while scrubbing to change the value by one step.
+pub const SCRUB_PIXELS_PER_STEP: f32 = 4.;
+
+#[derive(Copy, Clone, Debug)]
+pub struct NumberFieldSettings {
+    pub min: f32,
+    pub max: f32,
+    /// The amount the steppers and scrubbing change the value by.
+    pub step: f32,
+    /// The number of digits shown after the decimal point.
+    pub precision: usize,
+}
+
+impl Default for NumberFieldSettings {
+    fn default() -> Self {
+        Self {
+            min: f32::NEG_INFINITY,
+            max: f32::INFINITY,
+            step: 1.,
+            precision: 2,
+        }
+    }
+}
+
+impl NumberFieldSettings {
+    /// Limits the value to between the min and max.
+    fn clamp(&self, value: f32) -> f32 {
+        value.max(self.min).min(self.max)
+    }
+
+    /// Rounds the value to the nearest multiple of the step.
+    fn snap(&self, value: f32) -> f32 {
+        if self.step > 0. {
+            (value / self.step).round() * self.step
+        } else {
+            value
+        }
+    }
+}
+
+/// Reads typed text as a number limited to the settings' range.
+/// Returns `None` if the text isn't a finite number.
+fn parse_number(text: &str, settings: &NumberFieldSettings) -> Option<f32> {
+    let value = text.trim().parse::<f32>().ok()?;
+    if value.is_finite() {
+        Some(settings.clamp(value))
+    } else {
+        None
+    }
+}
+
+pub struct NumberField {
+    text: String,
+    /// The text couldn't be read as a number.
+    invalid: bool,
+    scrub: Drag,
+    /// The value when scrubbing started.
+    scrub_start: f32,
+    scrub_handle: Option<ElementHandle>,
+}
+
+impl NumberField {
+    fn new() -> Self {
+        Self {
+            text: String::new(),
+            invalid: false,
+            scrub: Drag::new((0., 0.)),
+            scrub_start: 0.,
+            scrub_handle: None,
+        }
+    }
+
+    /// Returns true if the value changed
+    fn build(
+        &mut self,
+        parent: &UIBuilder,
+        id: u64,
+        value: &mut f32,
+        settings: NumberFieldSettings,
+    ) -> bool {
+        let old_value = *value;
+        let text_field_id = super::calculate_id((id, 0));
+        let editing = parent.is_focused(text_field_id);
+        let snap_and_clamp = |v: f32| settings.clamp(settings.snap(v));
+
+        // Dragging the handle horizontally scrubs the value.
+        let was_scrubbing = self.scrub.dragging();
+        if let Some(handle) = self.scrub_handle {
+            // The handle doesn't move, so the position is how far the pointer has moved.
+            self.scrub.root_and_element = Some((handle, handle));
+        }
+        let position = self.scrub.update(parent);
+        if self.scrub.dragging() {
+            if !was_scrubbing {
+                self.scrub_start = *value;
+            }
+            let steps = (position.0 / SCRUB_PIXELS_PER_STEP).round();
+            *value = snap_and_clamp(self.scrub_start + steps * settings.step);
+        }
+
+        // Rendering
+        let row = parent.spaced_row(10.);
+        if super::button_with_id(&row, super::calculate_id((id, 1)), "−") {
+            *value = snap_and_clamp(*value - settings.step);
+        }
+
+        // Show the value unless it's being edited, in which case keep what was typed.
+        if !editing {
+            self.text = format!("{:.*}", settings.precision, *value);
+            self.invalid = false;
+        }
+        let border = if self.invalid {
+            NUMBER_FIELD_ERROR_COLOR
+        } else {
+            (0., 0., 0., 0.)
+        };
+        let field = row
+            .center_vertical()
+            .fit()
+            .fill(border)
+            .padding(2.)
+            .width(NUMBER_FIELD_WIDTH);
+        let response = super::text_field_with_id(&field, text_field_id, &mut self.text, "");
+        if response.changed {
+            match parse_number(&self.text, &settings) {
+                Some(typed) => {
+                    *value = typed;
+                    self.invalid = false;
+                }
+                None => self.invalid = true,
+            }
+        }
+        if response.submitted && !self.invalid {
+            parent.clear_focus();
+        }
+
+        if super::button_with_id(&row, super::calculate_id((id, 2)), "+") {
+            *value = snap_and_clamp(*value + settings.step);
+        }
+
+        let scrub_handle = row.center_vertical().fit();
+        scrub_handle.fill(SCRUB_HANDLE_COLOR).padding(10.).text("↔");
+        self.scrub_handle = Some(scrub_handle.handle());
+
+        *value != old_value
+    }
+}
+
+impl Widget for NumberField {}
+
+/// Create a field for editing a number by typing, with steppers, or by dragging the handle.
+/// Text that isn't a number is outlined and doesn't change the value.
+/// Returns true if the value changed.
+pub fn number_field_with_settings(
+    parent: &UIBuilder,
+    id: u64,
+    value: &mut f32,
+    settings: NumberFieldSettings,
+) -> bool {
+    let mut number_field = parent
+        .get_widget(id)
+        .1
+        .unwrap_or_else(|| Box::new(NumberField::new()));
+    let changed = number_field.build(parent, id, value, settings);
+    parent.add_widget(id, number_field);
+    changed
+}
+
+/// Create a field for editing a number by typing, with steppers, or by dragging the handle.
+/// Returns true if the value changed.
+pub fn number_field(parent: &UIBuilder, id: u64, value: &mut f32) -> bool {
+    number_field_with_settings(parent, id, value, NumberFieldSettings::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(min: f32, max: f32, step: f32) -> NumberFieldSettings {
+        NumberFieldSettings {
+            min,
+            max,
+            step,
+            ..NumberFieldSettings::default()
+        }
+    }
+
+    #[test]
+    fn parse_number_reads_numbers_with_whitespace() {
+        let settings = NumberFieldSettings::default();
+        assert_eq!(parse_number("42", &settings), Some(42.));
+        assert_eq!(parse_number("  -1.5 ", &settings), Some(-1.5));
+        assert_eq!(parse_number("1e3", &settings), Some(1000.));
+    }
+
+    #[test]
+    fn parse_number_rejects_text_that_isnt_a_number() {
+        let settings = NumberFieldSettings::default();
+        assert_eq!(parse_number("", &settings), None);
+        assert_eq!(parse_number("abc", &settings), None);
+        assert_eq!(parse_number("1.2.3", &settings), None);
+    }
+
+    #[test]
+    fn parse_number_rejects_infinity_and_nan() {
+        let settings = NumberFieldSettings::default();
+        assert_eq!(parse_number("inf", &settings), None);
+        assert_eq!(parse_number("-infinity", &settings), None);
+        assert_eq!(parse_number("NaN", &settings), None);
+    }
+
+    #[test]
+    fn parse_number_clamps_to_the_range() {
+        let settings = settings(0., 10., 1.);
+        assert_eq!(parse_number("-5", &settings), Some(0.));
+        assert_eq!(parse_number("15", &settings), Some(10.));
+        assert_eq!(parse_number("7.25", &settings), Some(7.25));
+    }
+
+    #[test]
+    fn snap_rounds_to_the_nearest_step() {
+        assert_eq!(settings(-100., 100., 0.5).snap(1.3), 1.5);
+        assert_eq!(settings(-100., 100., 5.).snap(-12.), -10.);
+        // Steps that aren't positive leave the value as it is.
+        assert_eq!(settings(-100., 100., 0.).snap(1.3), 1.3);
+        assert_eq!(settings(-100., 100., -1.).snap(1.3), 1.3);
+    }
+}
@@ -0,0 +1,67 @@
+use crate::ui::{UIBuilder, Widget};
+
+pub const PROGRESS_BAR_COLOR: (f32, f32, f32, f32) = (0.2, 0.2, 0.2, 1.0);
+pub const PROGRESS_BAR_FILLED_COLOR: (f32, f32, f32, f32) = (0.3, 0.45, 0.8, 1.0);
+pub const PROGRESS_BAR_HEIGHT: f32 = 8.;
+/// The width of the moving segment of an indeterminate progress bar as a fraction of the bar.
+pub const INDETERMINATE_SEGMENT_WIDTH: f32 = 0.3;
+/// Milliseconds for the moving segment to cross the bar.
+const INDETERMINATE_PERIOD: f32 = 1500.;
+
+/// Create a bar that's filled to show how far along something is.
+/// `fraction` is from 0. to 1.
+/// The bar fills the width of its parent, which can be set with `width`.
+pub fn progress_bar(parent: &UIBuilder, fraction: f32) {
+    let radius = PROGRESS_BAR_HEIGHT / 2.;
+    let bar = parent
+        .horizontal_expander()
+        .height(PROGRESS_BAR_HEIGHT)
+        .rounded_fill(PROGRESS_BAR_COLOR, radius);
+    let fraction = fraction.clamp(0., 1.);
+    if fraction > 0. {
+        bar.width_percentage(fraction)
+            .rounded_fill(PROGRESS_BAR_FILLED_COLOR, radius);
+    }
+}
+
+pub struct IndeterminateProgressBar {
+    /// 0. to 1. How far the segment is through crossing the bar.
+    phase: f32,
+}
+
+impl Widget for IndeterminateProgressBar {}
+
+/// Create a bar with a segment that moves across it for when progress isn't known.
+/// The bar requests an animation frame every time it's built, even if it's scrolled
+/// out of view, so the UI keeps redrawing until it's no longer built.
+/// Only build it while the work is in progress and the bar can be seen.
+/// The bar fills the width of its parent, which can be set with `width`.
+pub fn indeterminate_progress_bar_with_id(parent: &UIBuilder, id: u64) {
+    let mut state = parent
+        .get_widget(id)
+        .1
+        .unwrap_or_else(|| Box::new(IndeterminateProgressBar { phase: 0. }));
+    state.phase = (state.phase + parent.animation_delta() / INDETERMINATE_PERIOD).fract();
+    parent.request_animation_frame();
+
+    // The segment starts beyond the left edge and finishes beyond the right edge.
+    let position = state.phase * (1. + INDETERMINATE_SEGMENT_WIDTH) - INDETERMINATE_SEGMENT_WIDTH;
+    let radius = PROGRESS_BAR_HEIGHT / 2.;
+    parent
+        .horizontal_expander()
+        .height(PROGRESS_BAR_HEIGHT)
+        .rounded_fill(PROGRESS_BAR_COLOR, radius)
+        .clip()
+        .position_horizontal_percentage(position)
+        .width_percentage(INDETERMINATE_SEGMENT_WIDTH)
+        .rounded_fill(PROGRESS_BAR_FILLED_COLOR, radius);
+    parent.add_widget(id, state);
+}
+
+/// Create a bar with a segment that moves across it for when progress isn't known.
+/// Only build it while the bar can be seen, as it keeps the UI redrawing.
+#[track_caller]
+pub fn indeterminate_progress_bar(parent: &UIBuilder) {
+    let id = super::calculate_id(());
+    indeterminate_progress_bar_with_id(parent, id)
+}
@@ -0,0 +1,135 @@
+use super::Checkable;
+use crate::input::Key;
+use crate::ui::{ElementHandle, UIBuilder, Widget};
+
+pub const RADIO_SIZE: f32 = 30.;
+pub const RADIO_BORDER_COLOR: (f32, f32, f32, f32) = (0.8, 0.8, 0.8, 1.0);
+pub const RADIO_DOT_COLOR: (f32, f32, f32, f32) = (0.8, 0.8, 0.8, 1.0);
+/// The size of the dot in the selected option as a fraction of the option's size.
+pub const RADIO_DOT_SIZE: f32 = 0.5;
+/// Milliseconds for the dot animation to complete.
+const ANIMATION_TIME: f32 = 100.;
+
+pub struct RadioGroup {
+    /// The press and animation of each option.
+    checkables: Vec<Checkable>,
+    options: Vec<ElementHandle>,
+}
+
+impl RadioGroup {
+    fn new() -> Self {
+        Self {
+            checkables: Vec::new(),
+            options: Vec::new(),
+        }
+    }
+
+    /// Returns true if the selection changed
+    fn build(
+        &mut self,
+        parent: &UIBuilder,
+        id: u64,
+        selected: &mut usize,
+        options: &[&str],
+    ) -> bool {
+        // Options may have been added or removed since the last frame.
+        while self.checkables.len() < options.len() {
+            let checked = self.checkables.len() == *selected;
+            self.checkables.push(Checkable::new(checked));
+        }
+        self.checkables.truncate(options.len());
+
+        // Input
+        let mut new_selection = None;
+        for (i, checkable) in self.checkables.iter_mut().enumerate() {
+            if checkable.clicked(parent, id, self.options.get(i).copied()) {
+                new_selection = Some(i);
+            }
+        }
+
+        // Arrow keys move the selection within the group.
+        let focused = parent.is_focused(id);
+        if focused && !options.is_empty() {
+            let last = options.len() - 1;
+            for key in parent.keys_pressed() {
+                let current = new_selection.unwrap_or(*selected).min(last);
+                match key {
+                    Key::Up | Key::Left => new_selection = Some(current.saturating_sub(1)),
+                    Key::Down | Key::Right => new_selection = Some((current + 1).min(last)),
+                    Key::Home => new_selection = Some(0),
+                    Key::End => new_selection = Some(last),
+                    _ => {}
+                }
+            }
+        }
+
+        // The selection is only written when it's changed by the user.
+        let changed = match new_selection {
+            Some(new_selection) if new_selection != *selected => {
+                *selected = new_selection;
+                true
+            }
+            _ => false,
+        };
+
+        let selected = *selected;
+        let animation: Vec<f32> = self
+            .checkables
+            .iter_mut()
+            .enumerate()
+            .map(|(i, checkable)| checkable.animate(parent, i == selected, ANIMATION_TIME))
+            .collect();
+
+        // Rendering
+        let top = parent.fit();
+        top.focusable(id);
+        let column = top.spaced_column(10.);
+        self.options.clear();
+        for (i, option) in options.iter().enumerate() {
+            let option_root = column.fit();
+            let row = option_root.spaced_row(10.);
+            let dot_size = RADIO_SIZE * RADIO_DOT_SIZE * animation[i];
+            super::focus_ring(&row.center_vertical().fit(), focused && i == selected)
+                .width(RADIO_SIZE)
+                .height(RADIO_SIZE)
+                .rounded_fill(RADIO_BORDER_COLOR, RADIO_SIZE / 2.)
+                .padding(2.)
+                .rounded_fill((0., 0., 0., 1.), RADIO_SIZE / 2. - 2.)
+                .center()
+                .width(dot_size)
+                .height(dot_size)
+                .rounded_fill(RADIO_DOT_COLOR, dot_size / 2.);
+            row.center_vertical().text(option);
+            self.options.push(option_root.handle());
+        }
+
+        changed
+    }
+}
+
+impl Widget for RadioGroup {}
+
+pub fn radio_group_with_id(
+    parent: &UIBuilder,
+    id: u64,
+    selected: &mut usize,
+    options: &[&str],
+) -> bool {
+    let mut radio_group = parent
+        .get_widget(id)
+        .1
+        .unwrap_or_else(|| Box::new(RadioGroup::new()));
+    let changed = radio_group.build(parent, id, selected, options);
+    parent.add_widget(id, radio_group);
+    changed
+}
+
+/// Create a group of options where only one can be selected at a time.
+/// `selected` is the index of the selected option.
+/// Returns true if the selection changed.
+/// Uses the options for ID calculation.
+#[track_caller]
+pub fn radio_group(parent: &UIBuilder, selected: &mut usize, options: &[&str]) -> bool {
+    let id = super::calculate_id(options);
+    radio_group_with_id(parent, id, selected, options)
+}
@@ -1,37 +1,342 @@
-use crate::drawing_info::*;
+use super::drag::Drag;
 use crate::rectangle::Rectangle;
 use crate::render::Render;
 use crate::ui::{ElementHandle, TextProperties, UIBuilder, Widget, WidgetHandle};
 
 pub const HANDLE_COLOR: (f32, f32, f32, f32) = (0.2, 0.2, 0.2, 1.0);
+pub const TRACK_COLOR: (f32, f32, f32, f32) = (0.5, 0.5, 0.5, 0.2);
+pub const SCROLLBAR_WIDTH: f32 = 10.;
+pub const SCROLLBAR_MARGIN: f32 = 10.;
+
+/// How far the pointer must move before pressing on the content starts scrolling it.
+const DRAG_THRESHOLD: f32 = 8.;
+/// How much of the velocity remains after each millisecond of momentum.
+const FRICTION: f32 = 0.996;
+/// Momentum stops below this speed in pixels per millisecond.
+const MIN_VELOCITY: f32 = 0.01;
+/// Dragging past the edges only moves the content by this fraction of the pointer movement.
+const OVERSCROLL_RESISTANCE: f32 = 0.4;
+/// How much of the overscroll remains after each millisecond of bouncing back.
+const BOUNCE: f32 = 0.985;
+/// If the pointer is held still for this many milliseconds before release there is no momentum.
+const RELEASE_TIMEOUT: f32 = 60.;
+
+/// Tracks a pointer pressed on the content.
+struct Press {
+    start: (f32, f32),
+    last_position: (f32, f32),
+    last_move: std::time::Instant,
+}
+
+/// A scrollbar for one axis.
+/// The track and thumb are elements so that they can be hit tested and dragged.
+struct Scrollbar {
+    track: Option<ElementHandle>,
+    thumb: Option<ElementHandle>,
+    drag: Drag,
+}
+
+impl Scrollbar {
+    fn new() -> Self {
+        Self {
+            track: None,
+            thumb: None,
+            drag: Drag::new((0., 0.)),
+        }
+    }
+
+    fn build(&mut self, root: &UIBuilder) {
+        let track = root.rounded_fill(TRACK_COLOR, SCROLLBAR_WIDTH / 2.);
+        let thumb = root.rounded_fill(HANDLE_COLOR, SCROLLBAR_WIDTH / 2.);
+        self.track = Some(track.handle());
+        self.thumb = Some(thumb.handle());
+        self.drag.root_and_element = Some((track.handle(), thumb.handle()));
+    }
+
+    /// Returns the offset along this axis after dragging the thumb or clicking the track.
+    /// `axis` is 0 for horizontal and 1 for vertical.
+    fn update(
+        &mut self,
+        parent: &UIBuilder,
+        axis: usize,
+        offset: f32,
+        view_length: f32,
+        content_length: f32,
+    ) -> f32 {
+        let (track, thumb) = match (self.track, self.thumb) {
+            (Some(track), Some(thumb)) => (track, thumb),
+            _ => return offset,
+        };
+        let drag_position = self.drag.update(parent);
+        let track_rectangle = parent.element_rectangle(track);
+        let thumb_rectangle = parent.element_rectangle(thumb);
+        let pick = |r: Rectangle| {
+            if axis == 0 {
+                (r.x, r.width)
+            } else {
+                (r.y, r.height)
+            }
+        };
+        let (_, track_length) = pick(track_rectangle);
+        let (thumb_start, thumb_length) = pick(thumb_rectangle);
+        let scrollable = content_length - view_length;
+
+        if self.drag.dragging() && track_length > thumb_length {
+            let position = if axis == 0 {
+                drag_position.0
+            } else {
+                drag_position.1
+            };
+            let t = (position / (track_length - thumb_length)).clamp(0., 1.);
+            return -t * scrollable;
+        }
+
+        // Clicking the track outside of the thumb moves a page towards the pointer.
+        if parent.pointer_down()
+            && parent.pointer_in_element(track)
+            && !parent.pointer_in_element(thumb)
+        {
+            let pointer = if axis == 0 {
+                parent.pointer_position().0
+            } else {
+                parent.pointer_position().1
+            };
+            if pointer < thumb_start {
+                return offset + view_length;
+            } else if pointer > thumb_start + thumb_length {
+                return offset - view_length;
+            }
+        }
+        offset
+    }
+}
 
 pub struct ScrollView {
-    offset_y: f32,
+    /// How far the content is moved. Negative values move the content up and to the left.
+    offset: (f32, f32),
     view_and_content: Option<(ElementHandle, ElementHandle)>,
-    handle_rectangle: Option<(f32, f32, f32, f32)>,
+    /// The sizes of the view and content when last drawn.
+    view_size: (f32, f32),
+    content_size: (f32, f32),
+    horizontal_bar: Scrollbar,
+    vertical_bar: Scrollbar,
+    press: Option<Press>,
+    /// The content is following the pointer.
+    touch_dragging: bool,
+    /// Pixels per millisecond.
+    velocity: (f32, f32),
 }
 
 impl ScrollView {
     fn new() -> Self {
         Self {
-            offset_y: 0.,
+            offset: (0., 0.),
             view_and_content: None,
-            handle_rectangle: None,
+            view_size: (0., 0.),
+            content_size: (0., 0.),
+            horizontal_bar: Scrollbar::new(),
+            vertical_bar: Scrollbar::new(),
+            press: None,
+            touch_dragging: false,
+            velocity: (0., 0.),
         }
     }
 
-    /// Returns true if pressed
+    /// How far the offset is past the edges of the content.
+    fn overscroll(&self) -> (f32, f32) {
+        (
+            self.offset.0 - clamp_offset(self.offset.0, self.view_size.0, self.content_size.0),
+            self.offset.1 - clamp_offset(self.offset.1, self.view_size.1, self.content_size.1),
+        )
+    }
+
+    /// Overscroll and momentum are animating.
+    fn animating(&self) -> bool {
+        let overscroll = self.overscroll();
+        self.velocity != (0., 0.) || overscroll != (0., 0.)
+    }
+
+    /// Drag the content with the pointer and continue with momentum after release.
+    fn update_kinetic(&mut self, parent: &UIBuilder, view: ElementHandle) {
+        let delta_time = parent.animation_delta();
+        let pointer = parent.pointer_position();
+
+        // A widget that was pressed, like a slider being dragged, may have claimed the press.
+        if !self.touch_dragging && parent.pointer_captured() {
+            self.press = None;
+        }
+
+        let overscroll = self.overscroll();
+        if let Some(press) = &mut self.press {
+            let distance =
+                ((pointer.0 - press.start.0).powi(2) + (pointer.1 - press.start.1).powi(2)).sqrt();
+            if distance > DRAG_THRESHOLD && !self.touch_dragging {
+                // Scrolling takes the press from the widget it started on, such as a button.
+                self.touch_dragging = true;
+                parent.capture_pointer();
+            }
+
+            let delta = (
+                pointer.0 - press.last_position.0,
+                pointer.1 - press.last_position.1,
+            );
+            if self.touch_dragging && delta != (0., 0.) {
+                let resistance = |overscroll: f32| {
+                    if overscroll != 0. {
+                        OVERSCROLL_RESISTANCE
+                    } else {
+                        1.
+                    }
+                };
+                self.offset.0 += delta.0 * resistance(overscroll.0);
+                self.offset.1 += delta.1 * resistance(overscroll.1);
+
+                if delta_time > 0. {
+                    self.velocity = (delta.0 / delta_time, delta.1 / delta_time);
+                }
+                press.last_move = std::time::Instant::now();
+            }
+            press.last_position = pointer;
+
+            if parent.pointer_up() {
+                // A pointer held still before release shouldn't glide.
+                if press.last_move.elapsed().as_secs_f32() * 1000. > RELEASE_TIMEOUT {
+                    self.velocity = (0., 0.);
+                }
+                self.press = None;
+                self.touch_dragging = false;
+            }
+        } else {
+            // Continue with momentum, slowing down faster while past the edges.
+            let overscroll = self.overscroll();
+            let friction = |overscroll: f32| {
+                if overscroll != 0. {
+                    FRICTION.powi(8)
+                } else {
+                    FRICTION
+                }
+            };
+            self.offset.0 += self.velocity.0 * delta_time;
+            self.offset.1 += self.velocity.1 * delta_time;
+            self.velocity.0 *= friction(overscroll.0).powf(delta_time);
+            self.velocity.1 *= friction(overscroll.1).powf(delta_time);
+            if self.velocity.0.abs() < MIN_VELOCITY && self.velocity.1.abs() < MIN_VELOCITY {
+                self.velocity = (0., 0.);
+            }
+
+            // Bounce back from past the edges.
+            let overscroll = self.overscroll();
+            let bounce = BOUNCE.powf(delta_time);
+            for (offset, overscroll) in [
+                (&mut self.offset.0, overscroll.0),
+                (&mut self.offset.1, overscroll.1),
+            ]
+            .iter_mut()
+            {
+                if overscroll.abs() < 0.5 {
+                    **offset -= *overscroll;
+                } else {
+                    **offset -= *overscroll * (1. - bounce);
+                }
+            }
+        }
+
+        // Pressing on the content, but not the scrollbars, may start a drag.
+        let on_scrollbar = [&self.horizontal_bar, &self.vertical_bar]
+            .iter()
+            .any(|bar| bar.track.is_some_and(|t| parent.pointer_in_element(t)));
+        if parent.pointer_down() && parent.pointer_in_element(view) && !on_scrollbar {
+            self.press = Some(Press {
+                start: pointer,
+                last_position: pointer,
+                last_move: std::time::Instant::now(),
+            });
+            self.velocity = (0., 0.);
+        }
+
+        if self.animating() && self.press.is_none() {
+            parent.request_animation_frame();
+        }
+    }
+
+    /// Constrain the offset so the content stays within the view.
+    fn constrain(&mut self) {
+        self.offset.0 = clamp_offset(self.offset.0, self.view_size.0, self.content_size.0);
+        self.offset.1 = clamp_offset(self.offset.1, self.view_size.1, self.content_size.1);
+    }
+
     fn build<'a>(&mut self, parent: &UIBuilder<'a>, widget: WidgetHandle) -> UIBuilder<'a> {
-        self.offset_y += parent.scroll_delta();
+        let offset_before = self.offset;
+
+        // Scroll with the mouse wheel if the pointer is over the view.
+        // Holding shift scrolls vertical wheels horizontally.
+        if let Some((view, _)) = self.view_and_content {
+            if parent.pointer_in_element(view) {
+                let (mut delta_x, mut delta_y) = parent.scroll_delta();
+                if parent.modifiers().shift && delta_x == 0. {
+                    delta_x = delta_y;
+                    delta_y = 0.;
+                }
+                self.offset.0 += delta_x;
+                self.offset.1 += delta_y;
+            }
+        }
+
+        self.offset.0 = self.horizontal_bar.update(
+            parent,
+            0,
+            self.offset.0,
+            self.view_size.0,
+            self.content_size.0,
+        );
+        self.offset.1 = self.vertical_bar.update(
+            parent,
+            1,
+            self.offset.1,
+            self.view_size.1,
+            self.content_size.1,
+        );
+
+        if self.offset != offset_before {
+            // The wheel and scrollbars stop any momentum and don't overscroll.
+            self.velocity = (0., 0.);
+            self.constrain();
+        } else if let Some((view, _)) = self.view_and_content {
+            self.update_kinetic(parent, view);
+        }
 
         // The root should take up as much vertical space as the children needs,
         // but no more than the parent.
         let root = parent.flexible().custom_draw(widget);
         let inner = root.horizontal_expander().fit();
 
+        // The scrollbars are positioned when drawn.
+        self.horizontal_bar.build(&root);
+        self.vertical_bar.build(&root);
+
         self.view_and_content = Some((root.handle(), inner.handle()));
         inner
     }
+
+    /// Scroll the least amount needed so the rectangle is within the view.
+    fn scroll_to_rectangle(&mut self, view: Rectangle, rectangle: Rectangle) {
+        if rectangle.x < view.x {
+            self.offset.0 += view.x - rectangle.x;
+        } else if rectangle.x + rectangle.width > view.x + view.width {
+            self.offset.0 -= (rectangle.x + rectangle.width) - (view.x + view.width);
+        }
+        if rectangle.y < view.y {
+            self.offset.1 += view.y - rectangle.y;
+        } else if rectangle.y + rectangle.height > view.y + view.height {
+            self.offset.1 -= (rectangle.y + rectangle.height) - (view.y + view.height);
+        }
+        self.constrain();
+    }
+}
+
+/// Returns the offset along one axis limited so the content stays within the view.
+fn clamp_offset(offset: f32, view_length: f32, content_length: f32) -> f32 {
+    offset.min(0.).max((view_length - content_length).min(0.))
 }
 
 impl Widget for ScrollView {
@@ -47,55 +352,98 @@ impl Widget for ScrollView {
         text_properties: &TextProperties,
     ) {
         let (view, content) = self.view_and_content.unwrap();
-        let view_height = context.elements[view.0].rectangle.height;
-        let content_height = context.elements[content.0].rectangle.height;
-
-        // Constrain the scroll.
-        self.offset_y = self.offset_y.min(0.).max(-content_height + view_height);
+        self.view_size = context.elements[view.0].rectangle.width_height();
+        self.content_size = context.elements[content.0].rectangle.width_height();
+        if !self.touch_dragging && !self.animating() {
+            self.constrain();
+        }
 
         // The rectangle passed to the content of the scrollview.
         let child_rectangle = Rectangle::new(
-            rectangle.x,
-            self.offset_y,
+            rectangle.x + self.offset.0,
+            rectangle.y + self.offset.1,
             rectangle.width,
             rectangle.height,
         );
 
-        // Scrollbar settings.
-        let scrollbar_width = 10.;
-        let scrollbar_right_margin = 10.;
-
-        // First draw all content
-        for child in context.tree.child_iter(element) {
+        // First draw all content, clipped to the view.
+        // The first child contains the content, the others are the scrollbars.
+        context.push_clip(rectangle);
+        if let Some(child) = context.tree.child_iter(element).next() {
             context.render_element(text_properties, child_rectangle, child);
         }
+        context.pop_clip();
 
-        // Then draw the scrollbar on top
+        // Then draw the scrollbars on top, but only if they're needed.
+        let (view_width, view_height) = self.view_size;
+        let (content_width, content_height) = self.content_size;
+        let show_horizontal = view_width < content_width;
+        let show_vertical = view_height < content_height;
+        let corner = SCROLLBAR_WIDTH + SCROLLBAR_MARGIN;
 
-        // Don't draw the scrollbar if it's not needed.
-        if view_height < content_height {
-            let handle_height = (view_height / content_height) * view_height;
-            let handle_offset = view_height * (self.offset_y / content_height);
-            let fill_rectangle = (
-                rectangle.x + rectangle.width - scrollbar_width - scrollbar_right_margin,
-                rectangle.y - handle_offset,
-                scrollbar_width,
-                handle_height,
+        if show_vertical {
+            let track_height = if show_horizontal {
+                view_height - corner
+            } else {
+                view_height
+            };
+            let track_rectangle = Rectangle::new(
+                rectangle.x + rectangle.width - corner,
+                rectangle.y,
+                SCROLLBAR_WIDTH,
+                track_height,
+            );
+            let thumb_rectangle = Rectangle::new(
+                track_rectangle.x,
+                track_rectangle.y - track_height * (self.offset.1 / content_height),
+                SCROLLBAR_WIDTH,
+                (view_height / content_height) * track_height,
+            );
+            context.render_element(
+                text_properties,
+                track_rectangle,
+                self.vertical_bar.track.unwrap(),
+            );
+            context.render_element(
+                text_properties,
+                thumb_rectangle,
+                self.vertical_bar.thumb.unwrap(),
+            );
+        }
+
+        if show_horizontal {
+            let track_width = if show_vertical {
+                view_width - corner
+            } else {
+                view_width
+            };
+            let track_rectangle = Rectangle::new(
+                rectangle.x,
+                rectangle.y + rectangle.height - corner,
+                track_width,
+                SCROLLBAR_WIDTH,
+            );
+            let thumb_rectangle = Rectangle::new(
+                track_rectangle.x - track_width * (self.offset.0 / content_width),
+                track_rectangle.y,
+                (view_width / content_width) * track_width,
+                SCROLLBAR_WIDTH,
+            );
+            context.render_element(
+                text_properties,
+                track_rectangle,
+                self.horizontal_bar.track.unwrap(),
+            );
+            context.render_element(
+                text_properties,
+                thumb_rectangle,
+                self.horizontal_bar.thumb.unwrap(),
             );
-            context.drawing_info.drawables.push(Drawable {
-                rectangle: fill_rectangle,
-                texture_rectangle: (0., 0., 0., 0.),
-                color: HANDLE_COLOR,
-                radiuses: Some((10., 10., 10., 10.)),
-            });
-            self.handle_rectangle = Some(fill_rectangle);
-        } else {
-            self.handle_rectangle = None;
         }
     }
 }
 
-/// Returns the two sections divided by the divider
+/// Returns a container that scrolls horizontally and vertically when its content is larger than the view.
 pub fn scroll_view<'a>(parent: &UIBuilder<'a>, id: u64) -> UIBuilder<'a> {
     let (handle, item) = parent.get_widget(id);
     let mut item = item.unwrap_or(Box::new(ScrollView::new()));
@@ -103,3 +451,123 @@ pub fn scroll_view<'a>(parent: &UIBuilder<'a>, id: u64) -> UIBuilder<'a> {
     parent.add_widget(id, item);
     child_container
 }
+
+/// Scroll the scroll view with the ID so that the offset is at the upper left of the view.
+pub fn scroll_view_to_offset(parent: &UIBuilder, id: u64, offset: (f32, f32)) {
+    if let (_, Some(mut item)) = parent.get_widget::<ScrollView>(id) {
+        item.offset = (-offset.0, -offset.1);
+        item.constrain();
+        parent.add_widget(id, item);
+    }
+}
+
+/// Scroll the scroll view with the ID the least amount needed to show the element.
+/// The element's rectangle from the previous frame is used.
+pub fn scroll_view_to_element(parent: &UIBuilder, id: u64, element: ElementHandle) {
+    if let (_, Some(mut item)) = parent.get_widget::<ScrollView>(id) {
+        if let Some((view, _)) = item.view_and_content {
+            item.scroll_to_rectangle(
+                parent.element_rectangle(view),
+                parent.element_rectangle(element),
+            );
+        }
+        parent.add_widget(id, item);
+    }
+}
+
+/// Returns how far the scroll view with the ID is scrolled and the size of its view.
+/// The view size is from the previous frame.
+pub(crate) fn scroll_view_viewport(parent: &UIBuilder, id: u64) -> ((f32, f32), (f32, f32)) {
+    match parent.get_widget::<ScrollView>(id) {
+        (_, Some(item)) => {
+            let viewport = ((-item.offset.0, -item.offset.1), item.view_size);
+            parent.add_widget(id, item);
+            viewport
+        }
+        _ => ((0., 0.), (0., 0.)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::PointerButton;
+    use crate::ui::UI;
+
+    /// Builds and renders a frame with a slider and a column of buttons in a scroll view.
+    /// Returns how far the view is scrolled down and if a button was pressed.
+    fn scroll_frame(ui: &mut UI, value: &mut f32) -> (f32, bool) {
+        let mut pressed = false;
+        let offset = {
+            let root = ui.edit();
+            let content = scroll_view(&root, 1).column();
+            super::super::slider(&content, 2, value, 0.0..=1.0);
+            for i in 0..20 {
+                pressed |= super::super::button_with_id(&content, 10 + i, "Item");
+            }
+            (scroll_view_viewport(&root, 1).0).1
+        };
+        ui.render();
+        (offset, pressed)
+    }
+
+    /// The buttons are at the left, starting 100 pixels down, below the slider.
+    fn scrollable_ui(value: &mut f32) -> UI {
+        let mut ui = UI::new();
+        ui.resize(300., 200.);
+        scroll_frame(&mut ui, value);
+        scroll_frame(&mut ui, value);
+        ui
+    }
+
+    #[test]
+    fn tapping_a_button_presses_it() {
+        let mut value = 0.;
+        let mut ui = scrollable_ui(&mut value);
+        ui.pointer_down(20., 100., PointerButton::Primary);
+        scroll_frame(&mut ui, &mut value);
+        ui.pointer_up(20., 100., PointerButton::Primary);
+        let (offset, pressed) = scroll_frame(&mut ui, &mut value);
+        assert!(pressed);
+        assert_eq!(offset, 0.);
+    }
+
+    #[test]
+    fn dragging_from_a_button_scrolls_without_pressing_it() {
+        let mut value = 0.;
+        let mut ui = scrollable_ui(&mut value);
+        ui.pointer_down(20., 100., PointerButton::Primary);
+        scroll_frame(&mut ui, &mut value);
+        ui.pointer_move(20., 70.);
+        let (offset, pressed) = scroll_frame(&mut ui, &mut value);
+        assert!(!pressed);
+        assert_eq!(offset, 30.);
+
+        // The pointer is released over the button it was pressed on.
+        ui.pointer_up(20., 70., PointerButton::Primary);
+        let (_, pressed) = scroll_frame(&mut ui, &mut value);
+        assert!(!pressed);
+    }
+
+    #[test]
+    fn dragging_a_slider_doesnt_scroll() {
+        let mut value = 0.;
+        let mut ui = scrollable_ui(&mut value);
+        // The slider's bar is 30 pixels from its top.
+        ui.pointer_down(150., 36., PointerButton::Primary);
+        scroll_frame(&mut ui, &mut value);
+        ui.pointer_move(250., 6.);
+        let (offset, _) = scroll_frame(&mut ui, &mut value);
+        assert_eq!(offset, 0.);
+        assert!(value > 0.8);
+    }
+
+    #[test]
+    fn clamp_offset_keeps_the_content_in_the_view() {
+        assert_eq!(clamp_offset(10., 100., 300.), 0.);
+        assert_eq!(clamp_offset(-50., 100., 300.), -50.);
+        assert_eq!(clamp_offset(-250., 100., 300.), -200.);
+        // Content smaller than the view can't scroll.
+        assert_eq!(clamp_offset(-20., 100., 50.), 0.);
+    }
+}
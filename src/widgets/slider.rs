@@ -1,119 +1,198 @@
 use super::interpolation::*;
-use crate::ui::{ElementHandle, UIBuilder, UIEvent, UI};
-use crate::widget::Widget;
-pub const HANDLE_COLOR: (f32, f32, f32, f32) = (0.32, 0.32, 0.32, 1.0);
+use crate::input::Key;
+use crate::ui::{ElementHandle, UIBuilder, Widget};
+use std::ops::RangeInclusive;
+
+pub const SLIDER_HANDLE_COLOR: (f32, f32, f32, f32) = (0.32, 0.32, 0.32, 1.0);
 pub const SLIDER_COLOR: (f32, f32, f32, f32) = (0.74, 0.74, 0.74, 1.0);
 pub const SLIDER_FILLED_COLOR: (f32, f32, f32, f32) = (0.3, 0.3, 0.3, 1.0);
+pub const BAR_THICKNESS: f32 = 12.;
+pub const HANDLE_SIZE: f32 = 26.;
+/// How much larger the handle grows while hovered or dragged.
+pub const HANDLE_HOVER_GROWTH: f32 = 10.;
+/// Milliseconds for the hover animation to complete.
+const HOVER_ANIMATION_TIME: f32 = 100.;
+
+/// Options for a slider.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct SliderSettings {
+    /// If set the value snaps to multiples of the step from the start of the range.
+    /// Steps that aren't positive and finite are ignored.
+    pub step: Option<f32>,
+    /// The slider runs from bottom to top instead of left to right.
+    pub vertical: bool,
+}
 
 pub struct Slider {
     dragging_handle: bool,
-    pressed: bool,
-    handle_position: f32,
     handle: Option<ElementHandle>,
     bar: Option<ElementHandle>,
     element: Option<ElementHandle>,
+    /// 0. to 1. How much the handle has grown.
     hover_animate: Interpolate,
-    hovering_handle: bool,
 }
 
 impl Slider {
-    pub fn new() -> Self {
+    fn new() -> Self {
         Self {
-            // Handle position is from 0.0 to 1.0
-            handle_position: 0.0,
             dragging_handle: false,
-            pressed: false,
             handle: None,
             bar: None,
             element: None,
             hover_animate: Interpolate::new(InterpolationCurve::Ease),
-            hovering_handle: false,
         }
     }
 
-    /// This function resets when it is called.
-    pub fn pressed(&mut self) -> bool {
-        let b = self.pressed;
-        self.pressed = false;
-        b
+    /// The position of the pointer along the bar from 0.0 to 1.0.
+    fn pointer_position_on_bar(&self, parent: &UIBuilder, vertical: bool) -> f32 {
+        let bar_rectangle = parent.element_rectangle(self.bar.unwrap());
+        let pointer_position = parent.pointer_position();
+        let position = if vertical {
+            1.0 - (pointer_position.1 - bar_rectangle.y) / bar_rectangle.height
+        } else {
+            (pointer_position.0 - bar_rectangle.x) / bar_rectangle.width
+        };
+        position.clamp(0.0, 1.0)
     }
-}
 
-impl Slider {
-    fn build(&mut self, parent: &UIBuilder) {
-        let bar_height = 12.;
-        // Just using the  parent here is probably incorrect.
+    /// Returns true if the value changed.
+    fn build(
+        &mut self,
+        parent: &UIBuilder,
+        id: u64,
+        value: &mut f32,
+        range: RangeInclusive<f32>,
+        settings: SliderSettings,
+    ) -> bool {
+        let (start, end) = (*range.start(), *range.end());
+        let length = end - start;
+        let old_value = *value;
+        let step = settings.step.filter(|s| s.is_finite() && *s > 0.);
+
+        // Input
+        let mut hovering_handle = false;
+        if let (Some(element), Some(handle)) = (self.element, self.handle) {
+            hovering_handle = parent.pointer_in_element(handle);
+            // Clicking anywhere on the slider jumps the handle there.
+            if parent.pointer_down() && parent.pointer_in_element(element) {
+                parent.request_focus(id);
+                parent.capture_pointer();
+                self.dragging_handle = true;
+            }
+            if self.dragging_handle {
+                *value = start + self.pointer_position_on_bar(parent, settings.vertical) * length;
+            }
+            if parent.pointer_up() {
+                self.dragging_handle = false;
+            }
+        }
+
+        let focused = parent.is_focused(id);
+        if focused {
+            let step = step.unwrap_or(length / 100.);
+            for key in parent.keys_pressed() {
+                match key {
+                    Key::Left | Key::Down => *value -= step,
+                    Key::Right | Key::Up => *value += step,
+                    Key::PageDown => *value -= length / 10.,
+                    Key::PageUp => *value += length / 10.,
+                    Key::Home => *value = start,
+                    Key::End => *value = end,
+                    _ => {}
+                }
+            }
+        }
+
+        if let Some(step) = step {
+            *value = start + ((*value - start) / step).round() * step;
+        }
+        *value = value.max(start).min(end);
+
+        // Animate the handle growing while it's hovered or dragged.
+        let delta = parent.animation_delta() / HOVER_ANIMATION_TIME;
+        if hovering_handle || self.dragging_handle {
+            self.hover_animate.add(delta);
+        } else {
+            self.hover_animate.subtract(delta);
+        }
+        if self.hover_animate.not_one_or_zero() {
+            parent.request_animation_frame();
+        }
+
+        // Rendering
+        let handle_position = if length == 0. {
+            0.
+        } else {
+            (*value - start) / length
+        };
+        let handle_size = HANDLE_SIZE + self.hover_animate.get() * HANDLE_HOVER_GROWTH;
+        let handle_color = if focused {
+            super::FOCUS_RING_COLOR
+        } else {
+            SLIDER_HANDLE_COLOR
+        };
+
         let top = parent.fit();
-        let bar = top
-            .horizontal_expander()
-            .padding(30.)
-            .height(bar_height)
-            .rounded_fill(SLIDER_COLOR, bar_height / 2.);
-
-        let _filled_bar = bar
-            .width_percentage(self.handle_position)
-            .height(bar_height)
-            .rounded_fill(SLIDER_FILLED_COLOR, bar_height / 2.);
-
-        let handle_size = 26. + self.hover_animate.get() * 10.;
-        let handle = bar
-            .center_vertical()
-            .position_horizontal_percentage(self.handle_position)
-            .position_horizontal_pixels(-handle_size / 2.)
+        top.focusable(id);
+        let handle = if settings.vertical {
+            let bar = top
+                .vertical_expander()
+                .padding(30.)
+                .width(BAR_THICKNESS)
+                .rounded_fill(SLIDER_COLOR, BAR_THICKNESS / 2.);
+            // The filled part of a vertical slider grows up from the bottom.
+            bar.position_vertical_percentage(1.0 - handle_position)
+                .height_percentage(handle_position)
+                .width(BAR_THICKNESS)
+                .rounded_fill(SLIDER_FILLED_COLOR, BAR_THICKNESS / 2.);
+            self.bar = Some(bar.handle());
+            bar.center_horizontal()
+                .position_vertical_percentage(1.0 - handle_position)
+                .position_vertical_pixels(-handle_size / 2.)
+        } else {
+            let bar = top
+                .horizontal_expander()
+                .padding(30.)
+                .height(BAR_THICKNESS)
+                .rounded_fill(SLIDER_COLOR, BAR_THICKNESS / 2.);
+            bar.width_percentage(handle_position)
+                .height(BAR_THICKNESS)
+                .rounded_fill(SLIDER_FILLED_COLOR, BAR_THICKNESS / 2.);
+            self.bar = Some(bar.handle());
+            bar.center_vertical()
+                .position_horizontal_percentage(handle_position)
+                .position_horizontal_pixels(-handle_size / 2.)
+        };
+        let handle = handle
             .width(handle_size)
             .height(handle_size)
-            .rounded_fill(SLIDER_FILLED_COLOR, handle_size / 2.);
+            .rounded_fill(handle_color, handle_size / 2.);
 
-        self.bar = Some(bar.handle());
         self.handle = Some(handle.handle());
         self.element = Some(top.handle());
+        *value != old_value
     }
 }
-impl Widget for Slider {
-    fn event(&mut self, ui: &mut UI, event: UIEvent) {
-        match event {
-            UIEvent::AnimationFrame(delta) => {
-                let animation_speed = 100.;
-                if self.dragging_handle {
-                    self.hover_animate.add(delta / animation_speed);
-                } else {
-                    self.hover_animate.subtract(delta / animation_speed);
-                }
-                if self.hover_animate.not_one_or_zero() {
-                    ui.request_animation_frame();
-                }
-            }
-            UIEvent::PointerDown => {
-                if ui.pointer_in_element(self.element.unwrap()) {
-                    let slider_rectangle = ui.element_rectangle(self.bar.unwrap());
-                    let pointer_position = ui.pointer_position();
-                    let x_difference = ((pointer_position.0 - slider_rectangle.x)
-                        / slider_rectangle.width)
-                        .min(1.0)
-                        .max(0.0);
-                    self.handle_position = x_difference;
-                    self.dragging_handle = true;
-                }
-            }
-            UIEvent::PointerUp => self.dragging_handle = false,
-            UIEvent::PointerMoved => {
-                if self.dragging_handle {
-                    let slider_rectangle = ui.element_rectangle(self.bar.unwrap());
-                    let pointer_position = ui.pointer_position();
-                    let x_difference = ((pointer_position.0 - slider_rectangle.x)
-                        / slider_rectangle.width)
-                        .min(1.0)
-                        .max(0.0);
-
-                    self.handle_position = x_difference;
-                }
-                if ui.pointer_in_element(self.handle.unwrap()) {
-                    self.hovering_handle = true;
-                } else {
-                    self.hovering_handle = false;
-                }
-            }
-            _ => {}
-        }
-    }
+
+impl Widget for Slider {}
+
+/// Create a slider that edits `value` within `range`.
+/// Returns true if the value changed.
+pub fn slider_with_settings(
+    parent: &UIBuilder,
+    id: u64,
+    value: &mut f32,
+    range: RangeInclusive<f32>,
+    settings: SliderSettings,
+) -> bool {
+    let mut slider = parent.get_widget(id).1.unwrap_or(Box::new(Slider::new()));
+    let changed = slider.build(parent, id, value, range, settings);
+    parent.add_widget(id, slider);
+    changed
+}
+
+/// Create a horizontal slider that edits `value` within `range`.
+/// Returns true if the value changed.
+pub fn slider(parent: &UIBuilder, id: u64, value: &mut f32, range: RangeInclusive<f32>) -> bool {
+    slider_with_settings(parent, id, value, range, SliderSettings::default())
 }
@@ -0,0 +1,304 @@
+use super::drag::Drag;
+use crate::ui::{ElementHandle, UIBuilder, Widget};
+
+pub const TABLE_HEADER_COLOR: (f32, f32, f32, f32) = (0.25, 0.25, 0.25, 1.0);
+pub const TABLE_ROW_COLOR: (f32, f32, f32, f32) = (0.1, 0.1, 0.1, 1.0);
+pub const TABLE_ALTERNATE_ROW_COLOR: (f32, f32, f32, f32) = (0.14, 0.14, 0.14, 1.0);
+pub const TABLE_SELECTED_ROW_COLOR: (f32, f32, f32, f32) = (0.3, 0.45, 0.8, 1.0);
+pub const TABLE_DIVIDER_COLOR: (f32, f32, f32, f32) = (0.4, 0.4, 0.4, 1.0);
+pub const TABLE_ROW_HEIGHT: f32 = 40.;
+pub const TABLE_TEXT_SIZE: f32 = 22.;
+pub const DEFAULT_COLUMN_WIDTH: f32 = 200.;
+pub const MIN_COLUMN_WIDTH: f32 = 30.;
+/// The width of the draggable space between header cells.
+const DIVIDER_WIDTH: f32 = 6.;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+/// What happened to a table this frame.
+#[derive(Clone, Debug, Default)]
+pub struct TableResponse {
+    /// The column to sort by and its direction.
+    /// Clicking a header sorts by that column, and clicking it again reverses the direction.
+    /// The caller is responsible for sorting the rows.
+    pub sort: Option<(usize, SortDirection)>,
+    /// The sort column or direction changed this frame.
+    pub sort_changed: bool,
+    /// The selected rows in ascending order.
+    pub selected: Vec<usize>,
+    /// The selection changed this frame.
+    pub selection_changed: bool,
+}
+
+pub struct Table {
+    column_widths: Vec<f32>,
+    /// A drag for the divider on the right of each column.
+    dividers: Vec<Drag>,
+    sort: Option<(usize, SortDirection)>,
+    selected: Vec<usize>,
+    /// The row that Shift-clicking selects a range from.
+    selection_anchor: Option<usize>,
+    header_cells: Vec<ElementHandle>,
+    /// The header cell the pointer was pressed on, which sorts if released on the same cell.
+    pressed_header: Option<usize>,
+    /// The index and element of each row that was built.
+    rows: Vec<(usize, ElementHandle)>,
+}
+
+impl Table {
+    fn new() -> Self {
+        Self {
+            column_widths: Vec::new(),
+            dividers: Vec::new(),
+            sort: None,
+            selected: Vec::new(),
+            selection_anchor: None,
+            header_cells: Vec::new(),
+            pressed_header: None,
+            rows: Vec::new(),
+        }
+    }
+
+    fn handle_input(&mut self, parent: &UIBuilder, row_count: usize) -> TableResponse {
+        let mut response = TableResponse::default();
+
+        // Resize columns by dragging the divider to their right.
+        let mut left = 0.;
+        for (width, divider) in self.column_widths.iter_mut().zip(self.dividers.iter_mut()) {
+            let position = divider.update(parent);
+            if divider.dragging() {
+                *width = (position.0 - left).max(MIN_COLUMN_WIDTH);
+            }
+            left += *width + DIVIDER_WIDTH;
+        }
+
+        // Clicking a header sorts by its column.
+        // The pointer must be pressed and released on the same cell.
+        let hovered_header = self
+            .header_cells
+            .iter()
+            .position(|cell| parent.pointer_in_element(*cell));
+        if parent.pointer_down() {
+            self.pressed_header = hovered_header;
+        }
+        if parent.pointer_up() {
+            if let Some(i) = self
+                .pressed_header
+                .take()
+                .filter(|i| hovered_header == Some(*i))
+            {
+                self.sort = match self.sort {
+                    Some((column, SortDirection::Ascending)) if column == i => {
+                        Some((i, SortDirection::Descending))
+                    }
+                    _ => Some((i, SortDirection::Ascending)),
+                };
+                response.sort_changed = true;
+            }
+        }
+
+        // Clicking a row selects it.
+        // Control toggles a row and Shift selects a range from the last clicked row.
+        let modifiers = parent.modifiers();
+        let clicked = self
+            .rows
+            .iter()
+            .find(|(_, row)| parent.pointer_down() && parent.pointer_in_element(*row))
+            .map(|(index, _)| *index);
+        if let Some(index) = clicked {
+            match (modifiers.shift, self.selection_anchor) {
+                (true, Some(anchor)) => {
+                    self.selected = (anchor.min(index)..=anchor.max(index)).collect();
+                }
+                _ if modifiers.control || modifiers.meta => {
+                    if let Some(position) = self.selected.iter().position(|i| *i == index) {
+                        self.selected.remove(position);
+                    } else {
+                        self.selected.push(index);
+                        self.selected.sort_unstable();
+                    }
+                    self.selection_anchor = Some(index);
+                }
+                _ => {
+                    self.selected = vec![index];
+                    self.selection_anchor = Some(index);
+                }
+            }
+            response.selection_changed = true;
+        }
+
+        // Rows may have been removed.
+        self.selected.retain(|index| *index < row_count);
+
+        response.sort = self.sort;
+        response.selected = self.selected.clone();
+        response
+    }
+
+    fn build(
+        &mut self,
+        parent: &UIBuilder,
+        id: u64,
+        columns: &[&str],
+        row_count: usize,
+        cell: &mut dyn FnMut(usize, usize) -> String,
+    ) -> TableResponse {
+        self.column_widths
+            .resize(columns.len(), DEFAULT_COLUMN_WIDTH);
+        self.dividers
+            .resize_with(columns.len(), || Drag::new((0., 0.)));
+        let response = self.handle_input(parent, row_count);
+
+        // Rendering
+        let column = parent.text_size(TABLE_TEXT_SIZE).column();
+        // The header is built after the rows so it can follow their horizontal scrolling.
+        let header_view = column.height(TABLE_ROW_HEIGHT).clip();
+
+        // Only the visible rows are built.
+        let list_id = super::calculate_id(id);
+        let column_widths = &self.column_widths;
+        let selected = &self.selected;
+        let mut rows = Vec::new();
+        super::list_view(
+            &column.expander(),
+            list_id,
+            row_count,
+            TABLE_ROW_HEIGHT,
+            |row_builder, index| {
+                let color = if selected.contains(&index) {
+                    TABLE_SELECTED_ROW_COLOR
+                } else if index % 2 == 1 {
+                    TABLE_ALTERNATE_ROW_COLOR
+                } else {
+                    TABLE_ROW_COLOR
+                };
+                let row_root = row_builder.fit();
+                let row = row_root.fill(color).spaced_row(DIVIDER_WIDTH);
+                for (column, width) in column_widths.iter().enumerate() {
+                    row.width(*width)
+                        .height(TABLE_ROW_HEIGHT)
+                        .padding_horizontal(10.)
+                        .center_vertical()
+                        .ellipsis()
+                        .text(&cell(index, column));
+                }
+                rows.push((index, row_root.handle()));
+            },
+        );
+
+        let (scroll_offset, _) = super::scroll_view::scroll_view_viewport(parent, list_id);
+        let header_root = header_view
+            .position_horizontal_pixels(-scroll_offset.0)
+            .fit();
+        let header = header_root.row();
+        self.header_cells.clear();
+        for (i, title) in columns.iter().enumerate() {
+            let indicator = match self.sort {
+                Some((column, SortDirection::Ascending)) if column == i => " ▲",
+                Some((column, SortDirection::Descending)) if column == i => " ▼",
+                _ => "",
+            };
+            let header_cell = header.fit();
+            header_cell
+                .width(self.column_widths[i])
+                .height(TABLE_ROW_HEIGHT)
+                .fill(TABLE_HEADER_COLOR)
+                .padding_horizontal(10.)
+                .center_vertical()
+                .ellipsis()
+                .text(&format!("{}{}", title, indicator));
+            self.header_cells.push(header_cell.handle());
+
+            let divider = header.fit();
+            divider
+                .width(DIVIDER_WIDTH)
+                .height(TABLE_ROW_HEIGHT)
+                .fill(TABLE_HEADER_COLOR)
+                .padding_horizontal(DIVIDER_WIDTH / 2. - 1.)
+                .fill(TABLE_DIVIDER_COLOR);
+            self.dividers[i].root_and_element = Some((header_root.handle(), divider.handle()));
+        }
+        self.rows = rows;
+
+        response
+    }
+}
+
+impl Widget for Table {}
+
+/// Create a table with a header for each column and `row_count` rows.
+/// `cell` returns the text for a row and column, and is only called for visible rows.
+/// Text that doesn't fit in a cell is truncated with an ellipsis.
+pub fn table(
+    parent: &UIBuilder,
+    id: u64,
+    columns: &[&str],
+    row_count: usize,
+    mut cell: impl FnMut(usize, usize) -> String,
+) -> TableResponse {
+    let mut table = parent
+        .get_widget(id)
+        .1
+        .unwrap_or_else(|| Box::new(Table::new()));
+    let response = table.build(parent, id, columns, row_count, &mut cell);
+    parent.add_widget(id, table);
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::PointerButton;
+    use crate::ui::UI;
+
+    /// Builds and renders a frame with a table of 100 rows.
+    fn table_frame(ui: &mut UI) -> TableResponse {
+        let root = ui.edit();
+        let response = table(&root, 1, &["A", "B"], 100, |row, column| {
+            format!("{} {}", row, column)
+        });
+        drop(root);
+        ui.render();
+        response
+    }
+
+    /// Returns a table with half of the first row scrolled under the header.
+    fn scrolled_table() -> UI {
+        let mut ui = UI::new();
+        ui.resize(400., 300.);
+        table_frame(&mut ui);
+        ui.pointer_move(50., 150.);
+        ui.scroll(0., -TABLE_ROW_HEIGHT / 2.);
+        table_frame(&mut ui);
+        ui
+    }
+
+    #[test]
+    fn clicking_a_header_doesnt_select_the_row_scrolled_beneath_it() {
+        let mut ui = scrolled_table();
+        let (x, y) = (50., TABLE_ROW_HEIGHT * 0.75);
+        ui.pointer_down(x, y, PointerButton::Primary);
+        let response = table_frame(&mut ui);
+        assert!(!response.selection_changed);
+        assert!(response.selected.is_empty());
+
+        ui.pointer_up(x, y, PointerButton::Primary);
+        let response = table_frame(&mut ui);
+        assert!(response.sort_changed);
+        assert_eq!(response.sort, Some((0, SortDirection::Ascending)));
+    }
+
+    #[test]
+    fn clicking_a_row_selects_it() {
+        let mut ui = scrolled_table();
+        // The first row can be seen from the bottom of the header until 1.5 rows down.
+        ui.pointer_down(50., TABLE_ROW_HEIGHT * 1.25, PointerButton::Primary);
+        let response = table_frame(&mut ui);
+        assert!(response.selection_changed);
+        assert_eq!(response.selected, vec![0]);
+    }
+}
@@ -0,0 +1,219 @@
+use super::drag::Drag;
+use crate::ui::{ElementHandle, UIBuilder, Widget};
+
+pub const TAB_BAR_COLOR: (f32, f32, f32, f32) = (0.12, 0.12, 0.12, 1.0);
+pub const TAB_COLOR: (f32, f32, f32, f32) = (0.2, 0.2, 0.2, 1.0);
+pub const TAB_SELECTED_COLOR: (f32, f32, f32, f32) = (0.32, 0.32, 0.32, 1.0);
+pub const TAB_CLOSE_HOVER_COLOR: (f32, f32, f32, f32) = (0.45, 0.45, 0.45, 1.0);
+pub const TAB_TEXT_SIZE: f32 = 24.;
+pub const TAB_SPACING: f32 = 2.;
+
+/// The result of building a tab bar.
+pub struct Tabs<'a> {
+    /// The index of the selected tab.
+    pub selected: usize,
+    /// The tab whose close button was pressed this frame.
+    /// The caller is responsible for removing it.
+    pub closed: Option<usize>,
+    /// The tab at the first index was dragged to the second index this frame.
+    /// The caller is responsible for reordering the tabs.
+    pub moved: Option<(usize, usize)>,
+    /// The area beneath the tabs for the selected tab's content.
+    pub content: UIBuilder<'a>,
+}
+
+struct TabsState {
+    selected: usize,
+    /// How far the tabs are scrolled to the left, as a negative number.
+    scroll_offset: f32,
+    /// The tab whose close button is held down.
+    close_pressed: Option<usize>,
+    /// The tab being dragged.
+    dragged: Option<usize>,
+    drag: Drag,
+    bar: Option<ElementHandle>,
+    tabs: Vec<ElementHandle>,
+    close_buttons: Vec<ElementHandle>,
+}
+
+impl TabsState {
+    fn new() -> Self {
+        Self {
+            selected: 0,
+            scroll_offset: 0.,
+            close_pressed: None,
+            dragged: None,
+            drag: Drag::new((0., 0.)),
+            bar: None,
+            tabs: Vec::new(),
+            close_buttons: Vec::new(),
+        }
+    }
+
+    fn handle_input(
+        &mut self,
+        parent: &UIBuilder,
+        tab_count: usize,
+    ) -> (Option<usize>, Option<(usize, usize)>) {
+        let mut closed = None;
+        let mut moved = None;
+        let bar = match self.bar {
+            Some(bar) => bar,
+            None => return (closed, moved),
+        };
+        let bar_rectangle = parent.element_rectangle(bar);
+
+        // Scroll the tabs with the wheel when they don't fit.
+        if parent.pointer_in_element(bar) {
+            let (delta_x, delta_y) = parent.scroll_delta();
+            self.scroll_offset += if delta_x != 0. { delta_x } else { delta_y };
+        }
+        let tabs_width = match (self.tabs.first(), self.tabs.last()) {
+            (Some(first), Some(last)) => {
+                let first = parent.element_rectangle(*first);
+                let last = parent.element_rectangle(*last);
+                last.x + last.width - first.x
+            }
+            _ => 0.,
+        };
+        self.scroll_offset = self
+            .scroll_offset
+            .max(-(tabs_width - bar_rectangle.width).max(0.))
+            .min(0.);
+
+        // Close buttons are pressed when the pointer is released over them.
+        for (i, close_button) in self.close_buttons.iter().enumerate() {
+            if parent.pointer_in_element(*close_button) {
+                if parent.pointer_down() {
+                    self.close_pressed = Some(i);
+                }
+                if parent.pointer_up() && self.close_pressed == Some(i) {
+                    closed = Some(i);
+                }
+            }
+        }
+        if parent.pointer_up() {
+            self.close_pressed = None;
+        }
+
+        // Pressing a tab selects it and starts dragging it.
+        if parent.pointer_down() && self.close_pressed.is_none() {
+            for (i, tab) in self.tabs.iter().enumerate() {
+                if parent.pointer_in_element(*tab) {
+                    self.selected = i;
+                    self.dragged = Some(i);
+                    self.drag.root_and_element = Some((bar, *tab));
+                }
+            }
+        }
+        let position = self.drag.update(parent);
+        if !self.drag.dragging() {
+            // The handles are only valid for the frame they were built in.
+            self.dragged = None;
+            self.drag.root_and_element = None;
+        }
+
+        // Move the dragged tab when its center passes over another tab.
+        if let Some((dragged, dragged_tab)) = self
+            .dragged
+            .and_then(|dragged| Some((dragged, *self.tabs.get(dragged)?)))
+        {
+            let width = parent.element_rectangle(dragged_tab).width;
+            let center = bar_rectangle.x + position.0 + width / 2.;
+            for (i, tab) in self.tabs.iter().enumerate() {
+                let rectangle = parent.element_rectangle(*tab);
+                if i != dragged && center >= rectangle.x && center < rectangle.x + rectangle.width {
+                    moved = Some((dragged, i));
+                    self.dragged = Some(i);
+                    self.selected = i;
+                }
+            }
+        }
+
+        // Keep the selection on the same tab after another tab is closed.
+        if let Some(closed) = closed {
+            if closed < self.selected || (closed == self.selected && closed + 1 == tab_count) {
+                self.selected = self.selected.saturating_sub(1);
+            }
+        }
+        (closed, moved)
+    }
+
+    fn build<'a>(&mut self, parent: &UIBuilder<'a>, titles: &[&str]) -> Tabs<'a> {
+        let (closed, moved) = self.handle_input(parent, titles.len());
+        self.selected = self.selected.min(titles.len().saturating_sub(1));
+
+        // Rendering
+        let column = parent.column();
+        let bar = column.fit();
+        let strip = bar
+            .horizontal_expander()
+            .fill(TAB_BAR_COLOR)
+            .clip()
+            .position_horizontal_pixels(self.scroll_offset)
+            .text_size(TAB_TEXT_SIZE)
+            .spaced_row(TAB_SPACING);
+
+        let close_hovered: Vec<bool> = self
+            .close_buttons
+            .iter()
+            .map(|close_button| parent.pointer_in_element(*close_button))
+            .collect();
+        self.tabs.clear();
+        self.close_buttons.clear();
+        for (i, title) in titles.iter().enumerate() {
+            let color = if i == self.selected {
+                TAB_SELECTED_COLOR
+            } else {
+                TAB_COLOR
+            };
+            let tab = strip.fit();
+            let row = tab
+                .fill(color)
+                .padding_horizontal(15.)
+                .padding_vertical(10.)
+                .spaced_row(10.);
+            row.center_vertical().text(title);
+
+            let close_color = if close_hovered.get(i).copied().unwrap_or(false) {
+                TAB_CLOSE_HOVER_COLOR
+            } else {
+                (0., 0., 0., 0.)
+            };
+            let close_button = row.center_vertical().fit();
+            close_button
+                .rounded_fill(close_color, 4.)
+                .padding_horizontal(6.)
+                .text("×");
+
+            self.tabs.push(tab.handle());
+            self.close_buttons.push(close_button.handle());
+        }
+        self.bar = Some(bar.handle());
+        // Follow the dragged tab, which may have moved, with this frame's handles.
+        if let Some(dragged) = self.dragged {
+            self.drag.root_and_element = self.tabs.get(dragged).map(|tab| (bar.handle(), *tab));
+        }
+
+        Tabs {
+            selected: self.selected,
+            closed,
+            moved,
+            content: column.expander(),
+        }
+    }
+}
+
+impl Widget for TabsState {}
+
+/// Create a row of tabs with a content area beneath.
+/// Tabs can be selected, closed, reordered by dragging and scrolled when they don't fit.
+pub fn tabs<'a>(parent: &UIBuilder<'a>, id: u64, titles: &[&str]) -> Tabs<'a> {
+    let mut state = parent
+        .get_widget(id)
+        .1
+        .unwrap_or_else(|| Box::new(TabsState::new()));
+    let tabs = state.build(parent, titles);
+    parent.add_widget(id, state);
+    tabs
+}
@@ -1,34 +1,170 @@
 use crate::drawing_info::*;
+use crate::input::Key;
 use crate::rectangle::Rectangle;
 use crate::render::Render;
-use crate::ui::{ElementHandle, TextProperties, UIBuilder, Widget, WidgetHandle};
+use crate::ui::{ElementHandle, ElementType, TextProperties, UIBuilder, Widget, WidgetHandle};
 
 pub const DEFAULT_COLOR: (f32, f32, f32, f32) = (0.8, 0.8, 0.8, 1.0);
+/// The placeholder is drawn with the text color at this opacity.
+pub const PLACEHOLDER_OPACITY: f32 = 0.5;
+pub const CARET_WIDTH: f32 = 3.;
+
+/// What happened to a text field this frame.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct TextFieldResponse {
+    /// The text was edited.
+    pub changed: bool,
+    /// Enter was pressed while editing.
+    pub submitted: bool,
+}
 
 pub struct TextField {
     element: Option<ElementHandle>,
+    inner: Option<ElementHandle>,
+    text: Option<ElementHandle>,
+    /// Moves the text left so the caret stays visible.
+    scroll_element: Option<ElementHandle>,
+    /// How far the text is scrolled left in pixels.
+    scroll: f32,
+    /// The width of the area the text is visible in when last drawn.
+    view_width: f32,
+    /// The caret position in characters.
+    caret: usize,
+    /// The x position of each possible caret position, calculated when drawn.
+    caret_positions: Vec<f32>,
+    showing_placeholder: bool,
+    /// The text field is focused and shows a caret.
+    editing: bool,
 }
 
 impl TextField {
     fn new() -> Self {
-        Self { element: None }
+        Self {
+            element: None,
+            inner: None,
+            text: None,
+            scroll_element: None,
+            scroll: 0.,
+            view_width: 0.,
+            caret: 0,
+            caret_positions: Vec::new(),
+            showing_placeholder: false,
+            editing: false,
+        }
     }
 
-    /// Returns true if pressed
-    fn build(&mut self, parent: &UIBuilder, text: &str, widget: WidgetHandle) {
-        let top = parent.fit();
-        let root = parent.flexible().custom_draw(widget);
+    /// Returns the caret position closest to the x coordinate.
+    fn closest_caret(&self, x: f32) -> usize {
+        let mut closest = 0;
+        for (i, position) in self.caret_positions.iter().enumerate() {
+            if (position - x).abs() < (self.caret_positions[closest] - x).abs() {
+                closest = i;
+            }
+        }
+        closest
+    }
+
+    fn handle_input(
+        &mut self,
+        parent: &UIBuilder,
+        id: u64,
+        text: &mut String,
+    ) -> TextFieldResponse {
+        let mut response = TextFieldResponse::default();
+
+        if let Some(element) = self.element {
+            if parent.pointer_down() && parent.pointer_in_element(element) {
+                parent.request_focus(id);
+                self.caret = self.closest_caret(parent.pointer_position().0);
+            }
+        }
+
+        // The text may have been changed outside of the text field.
+        let character_count = text.chars().count();
+        self.caret = self.caret.min(character_count);
+
+        if !parent.is_focused(id) {
+            return response;
+        }
+
+        for key in parent.keys_pressed() {
+            match key {
+                Key::Backspace if self.caret > 0 => {
+                    self.caret -= 1;
+                    text.remove(byte_index(text, self.caret));
+                    response.changed = true;
+                }
+                Key::Delete if self.caret < text.chars().count() => {
+                    text.remove(byte_index(text, self.caret));
+                    response.changed = true;
+                }
+                Key::Left => self.caret = self.caret.saturating_sub(1),
+                Key::Right => self.caret = (self.caret + 1).min(text.chars().count()),
+                Key::Home => self.caret = 0,
+                Key::End => self.caret = text.chars().count(),
+                Key::Enter => response.submitted = true,
+                Key::Escape => parent.clear_focus(),
+                _ => {}
+            }
+        }
+
+        // Characters typed with Control or Meta held are shortcuts rather than text.
+        let modifiers = parent.modifiers();
+        if parent.is_focused(id) && !(modifiers.control || modifiers.meta) {
+            for c in parent.characters_typed().chars() {
+                text.insert(byte_index(text, self.caret), c);
+                self.caret += 1;
+                response.changed = true;
+            }
+        }
+        response
+    }
 
-        root.fill(DEFAULT_COLOR)
+    fn build(
+        &mut self,
+        parent: &UIBuilder,
+        id: u64,
+        text: &mut String,
+        placeholder_text: &str,
+        widget: WidgetHandle,
+    ) -> TextFieldResponse {
+        let response = self.handle_input(parent, id, text);
+        self.editing = parent.is_focused(id);
+
+        // Show the placeholder if there is no text.
+        self.showing_placeholder = text.is_empty();
+        let shown_text = if self.showing_placeholder {
+            placeholder_text
+        } else {
+            text
+        };
+
+        let root = parent.flexible().custom_draw(widget);
+        root.focusable(id);
+        let inner = super::focus_ring(&root.horizontal_expander(), self.editing)
+            .fill(DEFAULT_COLOR)
             .padding(2.)
             .fill((0., 0., 0., 1.))
-            .padding(20.)
-            .center_vertical()
-            .text(text);
-        self.element = Some(top.handle());
+            .padding(20.);
+        // The scroll is set when drawn because it depends on the glyph advances.
+        let scroll_element = inner.clip().position_horizontal_pixels(-self.scroll);
+        let text = scroll_element.center_vertical().text(shown_text);
+
+        self.element = Some(root.handle());
+        self.inner = Some(inner.handle());
+        self.text = Some(text.handle());
+        self.scroll_element = Some(scroll_element.handle());
+        response
     }
 }
 
+/// Converts a character index to a byte index within the string.
+fn byte_index(text: &str, character_index: usize) -> usize {
+    text.char_indices()
+        .nth(character_index)
+        .map_or(text.len(), |(i, _)| i)
+}
+
 impl Widget for TextField {
     // A custom draw implementation to draw the cursor.
     fn draw(
@@ -38,38 +174,111 @@ impl Widget for TextField {
         rectangle: Rectangle,
         text_properties: &TextProperties,
     ) {
-        // First draw all children
+        let font = text_properties.font.map(|font| &context.fonts[font.0]);
+
+        // Calculate how far along the text each caret position is using the glyph advances.
+        let mut caret_offsets = vec![0.];
+        if let (Some(font), false) = (font, self.showing_placeholder) {
+            if let ElementType::Text(ref text) = context.elements[self.text.unwrap().0].element_type
+            {
+                let mut x = 0.;
+                for c in text.chars() {
+                    x += font.metrics(c, text_properties.size).advance_width;
+                    caret_offsets.push(x);
+                }
+            }
+        }
+
+        // Scroll the least amount needed to keep the caret within the view,
+        // without leaving space after the end of the text.
+        if self.view_width > 0. {
+            let caret_offset = caret_offsets[self.caret.min(caret_offsets.len() - 1)];
+            if self.editing {
+                if caret_offset < self.scroll {
+                    self.scroll = caret_offset;
+                } else if caret_offset > self.scroll + self.view_width {
+                    self.scroll = caret_offset - self.view_width;
+                }
+            }
+            let text_width = caret_offsets[caret_offsets.len() - 1];
+            self.scroll = self.scroll.min(text_width - self.view_width).max(0.);
+        }
+        context.elements[self.scroll_element.unwrap().0].element_type =
+            ElementType::PositionHorizontalPixels(-self.scroll);
+
+        // Draw all children, fading the text if it's the placeholder.
+        let mut children_text_properties = *text_properties;
+        if self.showing_placeholder {
+            children_text_properties.color.3 *= PLACEHOLDER_OPACITY;
+        }
         for child in context.tree.child_iter(element) {
-            context.render_element(text_properties, rectangle, child);
+            context.render_element(&children_text_properties, rectangle, child);
+        }
+        let inner_rectangle = context.elements[self.inner.unwrap().0].rectangle;
+        self.view_width = inner_rectangle.width;
+
+        let text_rectangle = context.elements[self.text.unwrap().0].rectangle;
+        self.caret_positions = caret_offsets
+            .iter()
+            .map(|offset| text_rectangle.x + offset)
+            .collect();
+
+        let font = match font {
+            Some(font) => font,
+            None => return,
+        };
+
+        if !self.editing {
+            return;
         }
 
-        // Then draw cursor on top.
-        context.drawing_info.drawables.push(Drawable {
+        // Then draw the caret on top, centered vertically on the line.
+        let caret_height = font
+            .horizontal_line_metrics(text_properties.size)
+            .map_or(text_properties.size, |m| m.ascent - m.descent);
+        let caret_x = self.caret_positions[self.caret.min(self.caret_positions.len() - 1)];
+        context.push_drawable(Drawable {
             rectangle: (
-                rectangle.x + rectangle.width - 18.,
-                rectangle.y + 25.,
-                4.,
-                text_properties.size,
+                caret_x - CARET_WIDTH / 2.,
+                inner_rectangle.y + (inner_rectangle.height - caret_height) / 2.,
+                CARET_WIDTH,
+                caret_height,
             ),
             texture_rectangle: (0., 0., 0., 0.),
-            color: (1.0, 1., 1., 1.),
-            radiuses: Some((0., 0., 0., 0.)),
+            color: text_properties.color,
+            radiuses: None,
+            gradient: None,
+            image: None,
+            clip: None,
         });
     }
 }
 
-pub fn text_field_with_id(parent: &UIBuilder, id: u64, placeholder_text: &str) {
+/// Create a single line text field that edits `text`.
+/// The placeholder text is shown when `text` is empty.
+/// The field is as wide as its parent, so set its size with something like `width`.
+pub fn text_field_with_id(
+    parent: &UIBuilder,
+    id: u64,
+    text: &mut String,
+    placeholder_text: &str,
+) -> TextFieldResponse {
     let (handle, widget) = parent.get_widget(id);
     let mut widget = widget.unwrap_or(Box::new(TextField::new()));
-    widget.build(parent, placeholder_text, handle);
+    let response = widget.build(parent, id, text, placeholder_text, handle);
     parent.add_widget(id, widget);
+    response
 }
 
-/// Create a button
-/// Returns true if the button is pressed.
-/// Uses button text for ID calculation.
+/// Create a single line text field that edits `text`.
+/// Returns if the text was changed or submitted this frame.
+/// Uses placeholder text for ID calculation.
 #[track_caller]
-pub fn text_field(parent: &UIBuilder, placeholder_text: &str) {
+pub fn text_field(
+    parent: &UIBuilder,
+    text: &mut String,
+    placeholder_text: &str,
+) -> TextFieldResponse {
     let id = super::calculate_id(placeholder_text);
-    text_field_with_id(parent, id, placeholder_text)
+    text_field_with_id(parent, id, text, placeholder_text)
 }
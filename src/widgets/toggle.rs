@@ -0,0 +1,84 @@
+use super::interpolation::*;
+use super::Checkable;
+use crate::ui::{ElementHandle, UIBuilder, Widget};
+
+pub const TOGGLE_WIDTH: f32 = 60.;
+pub const TOGGLE_HEIGHT: f32 = 32.;
+pub const TOGGLE_OFF_COLOR: (f32, f32, f32, f32) = (0.32, 0.32, 0.32, 1.0);
+pub const TOGGLE_ON_COLOR: (f32, f32, f32, f32) = (0.3, 0.7, 0.4, 1.0);
+pub const TOGGLE_KNOB_COLOR: (f32, f32, f32, f32) = (0.9, 0.9, 0.9, 1.0);
+/// The space between the knob and the edge of the track.
+const KNOB_MARGIN: f32 = 3.;
+/// Milliseconds for the knob to slide across.
+const ANIMATION_TIME: f32 = 150.;
+
+pub struct Toggle {
+    checkable: Checkable,
+    element: Option<ElementHandle>,
+}
+
+impl Toggle {
+    fn new(on: bool) -> Self {
+        Self {
+            checkable: Checkable::new(on),
+            element: None,
+        }
+    }
+
+    /// Returns true if toggled
+    fn build(&mut self, parent: &UIBuilder, id: u64, on: &mut bool, label: &str) -> bool {
+        // Input
+        let toggled = self.checkable.clicked(parent, id, self.element)
+            || super::pressed_with_keyboard(parent, id);
+        if toggled {
+            *on = !*on;
+        }
+        let t = self.checkable.animate(parent, *on, ANIMATION_TIME);
+        let focused = parent.is_focused(id);
+
+        // Rendering
+        let knob_size = TOGGLE_HEIGHT - KNOB_MARGIN * 2.;
+        let knob_travel = TOGGLE_WIDTH - knob_size - KNOB_MARGIN * 2.;
+
+        let top = parent.fit();
+        top.focusable(id);
+        let row = top.spaced_row(10.);
+        super::focus_ring(&row.center_vertical().fit(), focused)
+            .width(TOGGLE_WIDTH)
+            .height(TOGGLE_HEIGHT)
+            .rounded_fill(
+                interpolate_color(TOGGLE_OFF_COLOR, TOGGLE_ON_COLOR, t),
+                TOGGLE_HEIGHT / 2.,
+            )
+            .padding(KNOB_MARGIN)
+            .position_horizontal_pixels(knob_travel * t)
+            .width(knob_size)
+            .height(knob_size)
+            .rounded_fill(TOGGLE_KNOB_COLOR, knob_size / 2.);
+        row.center_vertical().text(label);
+
+        self.element = Some(top.handle());
+        toggled
+    }
+}
+
+impl Widget for Toggle {}
+
+pub fn toggle_with_id(parent: &UIBuilder, id: u64, on: &mut bool, label: &str) -> bool {
+    let mut toggle = parent
+        .get_widget(id)
+        .1
+        .unwrap_or_else(|| Box::new(Toggle::new(*on)));
+    let toggled = toggle.build(parent, id, on, label);
+    parent.add_widget(id, toggle);
+    toggled
+}
+
+/// Create a toggle switch with a label that edits `on`.
+/// Returns true if the switch was toggled.
+/// Uses label text for ID calculation.
+#[track_caller]
+pub fn toggle(parent: &UIBuilder, on: &mut bool, label: &str) -> bool {
+    let id = super::calculate_id(label);
+    toggle_with_id(parent, id, on, label)
+}
@@ -0,0 +1,121 @@
+use crate::ui::{ElementHandle, UIBuilder, Widget};
+
+pub const TOOLTIP_COLOR: (f32, f32, f32, f32) = (0.2, 0.2, 0.2, 0.95);
+pub const TOOLTIP_TEXT_SIZE: f32 = 20.;
+/// Milliseconds the pointer must rest over an element before its tooltip is shown.
+pub const TOOLTIP_DELAY: f32 = 500.;
+/// Where the tooltip is placed relative to the pointer.
+const POINTER_OFFSET: (f32, f32) = (12., 20.);
+
+pub struct Tooltip {
+    target: Option<ElementHandle>,
+    tooltip: Option<ElementHandle>,
+    /// Milliseconds the pointer has been over the target.
+    hover_time: f32,
+    /// Pressing on the target hides the tooltip until the pointer leaves.
+    dismissed: bool,
+}
+
+impl Tooltip {
+    fn new() -> Self {
+        Self {
+            target: None,
+            tooltip: None,
+            hover_time: 0.,
+            dismissed: false,
+        }
+    }
+
+    fn build(&mut self, parent: &UIBuilder, text: &str, delay: f32) {
+        let hovering = self.target.is_some_and(|t| parent.pointer_in_element(t));
+        if !hovering {
+            self.hover_time = 0.;
+            self.dismissed = false;
+        } else if parent.pointer_down() {
+            self.dismissed = true;
+        }
+
+        let mut showing = false;
+        if hovering && !self.dismissed {
+            self.hover_time += parent.animation_delta();
+            if self.hover_time < delay {
+                // Keep drawing frames until the tooltip appears.
+                parent.request_animation_frame();
+            } else {
+                showing = true;
+            }
+        }
+
+        // The size of the tooltip is known from the last frame it was shown.
+        let previous_size = self
+            .tooltip
+            .map(|t| parent.element_rectangle(t).width_height());
+        self.target = Some(parent.handle());
+        self.tooltip = None;
+        if !showing {
+            return;
+        }
+
+        // Keep the tooltip within the window.
+        let pointer_position = parent.pointer_position();
+        let window_size = parent.window_size();
+        let (width, height) = match previous_size {
+            Some(size) => size,
+            None => {
+                // The size isn't known until the tooltip is drawn, so draw again to position it.
+                parent.request_animation_frame();
+                (0., 0.)
+            }
+        };
+        let mut x = pointer_position.0 + POINTER_OFFSET.0;
+        let mut y = pointer_position.1 + POINTER_OFFSET.1;
+        if x + width > window_size.0 {
+            x = (window_size.0 - width).max(0.);
+        }
+        if y + height > window_size.1 {
+            // Flip above the pointer instead of covering it.
+            y = (pointer_position.1 - height - POINTER_OFFSET.1).max(0.);
+        }
+
+        let tooltip = parent
+            .overlay()
+            .position_horizontal_pixels(x)
+            .position_vertical_pixels(y)
+            .fit();
+        tooltip
+            .fill(TOOLTIP_COLOR)
+            .padding(10.)
+            .text_size(TOOLTIP_TEXT_SIZE)
+            .text(text);
+        self.tooltip = Some(tooltip.handle());
+    }
+}
+
+impl Widget for Tooltip {}
+
+/// Show a tooltip after the pointer rests over the parent for `delay` milliseconds.
+/// The parent should be the size of the element, for example a `fit`.
+pub fn tooltip_with_id(parent: &UIBuilder, id: u64, text: &str, delay: f32) {
+    let mut tooltip = parent
+        .get_widget(id)
+        .1
+        .unwrap_or_else(|| Box::new(Tooltip::new()));
+    tooltip.build(parent, text, delay);
+    parent.add_widget(id, tooltip);
+}
+
+/// Show a tooltip after the pointer rests over the parent for `delay` milliseconds.
+/// Uses the text for ID calculation.
+#[track_caller]
+pub fn tooltip_with_delay(parent: &UIBuilder, text: &str, delay: f32) {
+    let id = super::calculate_id(text);
+    tooltip_with_id(parent, id, text, delay)
+}
+
+/// Show a tooltip after the pointer rests over the parent.
+/// Uses the text for ID calculation.
+#[track_caller]
+pub fn tooltip(parent: &UIBuilder, text: &str) {
+    let id = super::calculate_id(text);
+    tooltip_with_id(parent, id, text, TOOLTIP_DELAY)
+}
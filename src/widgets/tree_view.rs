@@ -0,0 +1,197 @@
+use crate::input::Key;
+use crate::ui::{ElementHandle, UIBuilder, Widget};
+use std::collections::HashSet;
+
+pub const TREE_SELECTED_COLOR: (f32, f32, f32, f32) = (0.3, 0.45, 0.8, 1.0);
+/// The color of the selected node when the tree doesn't have focus.
+pub const TREE_UNFOCUSED_SELECTED_COLOR: (f32, f32, f32, f32) = (0.3, 0.3, 0.3, 1.0);
+/// How far each level of the tree is indented.
+pub const TREE_INDENT: f32 = 20.;
+const DISCLOSURE_WIDTH: f32 = 24.;
+const DISCLOSURE_TEXT_SIZE: f32 = 14.;
+
+/// A tree or an expanded node that nodes can be added to.
+pub struct TreeView<'a> {
+    builder: UIBuilder<'a>,
+    id: u64,
+    depth: usize,
+    parent_node: Option<u64>,
+}
+
+/// A node that was built last frame, in the order they're shown.
+struct VisibleNode {
+    id: u64,
+    parent: Option<u64>,
+    has_children: bool,
+    row: ElementHandle,
+    disclosure: ElementHandle,
+}
+
+struct TreeViewState {
+    /// The nodes whose children are shown.
+    expanded: HashSet<u64>,
+    selected: Option<u64>,
+    focused: bool,
+    nodes: Vec<VisibleNode>,
+}
+
+impl TreeViewState {
+    fn new() -> Self {
+        Self {
+            expanded: HashSet::new(),
+            selected: None,
+            focused: false,
+            nodes: Vec::new(),
+        }
+    }
+
+    fn handle_input(&mut self, parent: &UIBuilder, id: u64, selected: &mut Option<u64>) {
+        // Clicking the triangle expands or collapses a node and clicking elsewhere selects it.
+        if parent.pointer_down() {
+            for node in &self.nodes {
+                if node.has_children && parent.pointer_in_element(node.disclosure) {
+                    if !self.expanded.remove(&node.id) {
+                        self.expanded.insert(node.id);
+                    }
+                } else if parent.pointer_in_element(node.row) {
+                    *selected = Some(node.id);
+                    parent.request_focus(id);
+                }
+            }
+        }
+
+        self.focused = parent.is_focused(id);
+        if !self.focused || self.nodes.is_empty() {
+            return;
+        }
+
+        // Arrow keys move through the visible nodes.
+        // Right expands a node and then moves to its first child.
+        // Left collapses a node and then moves to its parent.
+        for key in parent.keys_pressed() {
+            let index = selected.and_then(|s| self.nodes.iter().position(|n| n.id == s));
+            let last = self.nodes.len() - 1;
+            let new_index = match (key, index) {
+                (Key::Up, Some(i)) => Some(i.saturating_sub(1)),
+                (Key::Down, Some(i)) => Some((i + 1).min(last)),
+                (Key::Up, None) | (Key::Down, None) | (Key::Home, _) => Some(0),
+                (Key::End, _) => Some(last),
+                (Key::Right, Some(i)) => {
+                    let node = &self.nodes[i];
+                    if !node.has_children || self.expanded.insert(node.id) {
+                        None
+                    } else {
+                        self.nodes
+                            .get(i + 1)
+                            .filter(|child| child.parent == Some(node.id))
+                            .map(|_| i + 1)
+                    }
+                }
+                (Key::Left, Some(i)) => {
+                    let node = &self.nodes[i];
+                    if self.expanded.remove(&node.id) {
+                        None
+                    } else {
+                        node.parent
+                            .and_then(|p| self.nodes.iter().position(|n| n.id == p))
+                    }
+                }
+                _ => None,
+            };
+            if let Some(new_index) = new_index {
+                *selected = Some(self.nodes[new_index].id);
+            }
+        }
+    }
+}
+
+impl Widget for TreeViewState {}
+
+fn get_state(parent: &UIBuilder, id: u64) -> Box<TreeViewState> {
+    parent
+        .get_widget(id)
+        .1
+        .unwrap_or_else(|| Box::new(TreeViewState::new()))
+}
+
+/// Create a tree that nodes can be added to with `tree_node`.
+/// `selected` is the ID of the selected node.
+/// The tree can be navigated with the arrow keys when focused.
+pub fn tree_view<'a>(parent: &UIBuilder<'a>, id: u64, selected: &mut Option<u64>) -> TreeView<'a> {
+    let mut state = get_state(parent, id);
+    state.handle_input(parent, id, selected);
+    state.selected = *selected;
+    // Nodes are added again as they're built this frame.
+    state.nodes.clear();
+    parent.add_widget(id, state);
+
+    let top = parent.fit();
+    top.focusable(id);
+    TreeView {
+        builder: top.column(),
+        id,
+        depth: 0,
+        parent_node: None,
+    }
+}
+
+/// Add a node to a tree, or a child to an expanded node.
+/// Node IDs must be unique within the tree and are used to remember which nodes are expanded.
+/// Nodes with children show a triangle that expands or collapses them.
+/// Returns the node to add children to, or `None` if it's collapsed, so children are
+/// only built for expanded nodes.
+pub fn tree_node<'a>(
+    tree: &TreeView<'a>,
+    node_id: u64,
+    label: &str,
+    has_children: bool,
+) -> Option<TreeView<'a>> {
+    let builder = &tree.builder;
+    let mut state = get_state(builder, tree.id);
+    let expanded = has_children && state.expanded.contains(&node_id);
+
+    let background = match (state.selected == Some(node_id), state.focused) {
+        (true, true) => TREE_SELECTED_COLOR,
+        (true, false) => TREE_UNFOCUSED_SELECTED_COLOR,
+        (false, _) => (0., 0., 0., 0.),
+    };
+    let row_root = builder.fit();
+    let row = row_root
+        .horizontal_expander()
+        .fill(background)
+        .padding_vertical(4.)
+        .row();
+    row.width(tree.depth as f32 * TREE_INDENT);
+    let disclosure = row.center_vertical().fit();
+    let glyph = match (has_children, expanded) {
+        (false, _) => "",
+        (true, false) => "▶",
+        (true, true) => "▼",
+    };
+    disclosure
+        .width(DISCLOSURE_WIDTH)
+        .center()
+        .text_size(DISCLOSURE_TEXT_SIZE)
+        .text(glyph);
+    row.center_vertical().text(label);
+
+    state.nodes.push(VisibleNode {
+        id: node_id,
+        parent: tree.parent_node,
+        has_children,
+        row: row_root.handle(),
+        disclosure: disclosure.handle(),
+    });
+    builder.add_widget(tree.id, state);
+
+    if expanded {
+        Some(TreeView {
+            builder: builder.clone(),
+            id: tree.id,
+            depth: tree.depth + 1,
+            parent_node: Some(node_id),
+        })
+    } else {
+        None
+    }
+}
@@ -1,6 +1,10 @@
 use super::drag::Drag;
+use crate::input::Key;
 use crate::ui::{UIBuilder, Widget};
 
+/// How far the divider moves per arrow key press when focused.
+const KEYBOARD_STEP: f32 = 10.;
+
 pub const DEFAULT_COLOR: (f32, f32, f32, f32) = (0.25, 0.25, 0.25, 1.0);
 
 pub struct VerticalDivider {
@@ -17,16 +21,43 @@ impl VerticalDivider {
     }
 
     /// Returns true if pressed
-    fn build<'a>(&mut self, parent: &UIBuilder<'a>) -> (UIBuilder<'a>, UIBuilder<'a>) {
+    fn build<'a>(&mut self, parent: &UIBuilder<'a>, id: u64) -> (UIBuilder<'a>, UIBuilder<'a>) {
         // Move the handle
-        self.first_section_width = self.drag.update(parent).0;
+        let mut position = self.drag.update(parent);
+
+        // Clicking the handle focuses the divider.
+        if let Some((_, handle)) = self.drag.root_and_element {
+            if parent.pointer_down() && parent.pointer_in_element(handle) {
+                parent.request_focus(id);
+            }
+        }
+
+        // The arrow keys move a focused divider.
+        let focused = parent.is_focused(id);
+        if focused {
+            if parent.key_pressed(Key::Left) {
+                position.0 -= KEYBOARD_STEP;
+            }
+            if parent.key_pressed(Key::Right) {
+                position.0 += KEYBOARD_STEP;
+            }
+            position.0 = position.0.max(0.);
+            self.drag.set_position(position);
+        }
+        self.first_section_width = position.0;
 
         // Rendering
         let row = parent.row();
         let first_section = row.width(self.first_section_width);
         let handle = row.fit();
+        handle.focusable(id);
         let handle_inner = handle.padding_horizontal(20.).vertical_expander();
-        handle_inner.width(2.).fill(DEFAULT_COLOR);
+        let color = if focused {
+            super::FOCUS_RING_COLOR
+        } else {
+            DEFAULT_COLOR
+        };
+        handle_inner.width(2.).fill(color);
         let second_section = row.expander();
 
         self.drag.root_and_element = Some((row.handle(), handle.handle()));
@@ -47,7 +78,7 @@ pub fn vertical_divider<'a>(
         .get_widget(id)
         .1
         .unwrap_or(Box::new(VerticalDivider::new(initial_first_section_width)));
-    let sections = item.build(parent);
+    let sections = item.build(parent, id);
     parent.add_widget(id, item);
     sections
 }